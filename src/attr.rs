@@ -3,9 +3,13 @@
 //! Note that you may create your own attribute by using [`Attribute::new`] or [`Attribute::new_flag`]
 //! Or by leveraging on of the `From` implementation on [`Attribute`]
 
-use alloc::{borrow::Cow, string::String};
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+use core::fmt::Write;
 
-use crate::Attribute;
+use crate::{Attribute, AttributeValue};
+
+#[cfg(feature = "alpine")]
+pub mod alpine;
 
 impl<T: Into<Cow<'static, str>>> From<(&'static str, T)> for Attribute {
     fn from((key, value): (&'static str, T)) -> Self {
@@ -13,6 +17,28 @@ impl<T: Into<Cow<'static, str>>> From<(&'static str, T)> for Attribute {
     }
 }
 
+/// `None` renders nothing (same as [`none`])
+///
+/// This allows to mix optional and mandatory attributes in the same list.
+/// (An iterator of `Option<Attribute>` can also be passed with `.into_iter().flatten()`)
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::{class, id}, elt::div};
+/// let active = false;
+/// let element = div(
+///     [id("x").into(), Some(class(["foo"])).into(), active.then(|| class(["active"])).into()],
+///     [],
+/// );
+/// assert_eq!(element.to_string(), r#"<div id="x" class="foo"></div>"#);
+/// ```
+impl From<Option<Attribute>> for Attribute {
+    fn from(value: Option<Attribute>) -> Self {
+        value.unwrap_or_else(none)
+    }
+}
+
 /// Do not render any attribute. Useful for conditional rendering.
 ///
 /// # Example
@@ -62,11 +88,100 @@ pub fn class<'a>(classes: impl IntoIterator<Item = &'a str>) -> Attribute {
     Attribute::new("class", values)
 }
 
+/// `class` attribute, ignoring empty and duplicated class names
+///
+/// This makes it easy to add classes conditionally.
+/// Surrounding whitespace is trimmed before comparing the class names,
+/// and the order of the first occurrence of each class is preserved.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::attr::classes;
+/// let active = false;
+/// assert_eq!(
+///   classes(["btn", if active { "btn-active" } else { "" }, "btn"]).to_string(),
+///   r#"class="btn""#,
+/// );
+/// ```
+pub fn classes(classes: impl IntoIterator<Item = impl Into<Cow<'static, str>>>) -> Attribute {
+    let mut unique: Vec<Cow<'static, str>> = Vec::new();
+    for class in classes {
+        let class = match class.into() {
+            Cow::Borrowed(class) => Cow::Borrowed(class.trim()),
+            Cow::Owned(class) if class.trim().len() == class.len() => Cow::Owned(class),
+            Cow::Owned(class) => Cow::Owned(class.trim().into()),
+        };
+        if !class.is_empty() && !unique.contains(&class) {
+            unique.push(class);
+        }
+    }
+    Attribute::new("class", unique.join(" "))
+}
+
+/// `data-*` attribute
+///
+/// The key is prefixed with `data-`.
+///
+/// ## Panics
+///
+/// Panics if the key is empty or contains anything else than lowercase ASCII letters, digits, `-` and `_`.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::attr::data;
+/// assert_eq!(
+///   data("user-id", "42").to_string(),
+///   r#"data-user-id="42""#,
+/// );
+/// ```
+pub fn data(key: impl Into<Cow<'static, str>>, value: impl Into<Cow<'static, str>>) -> Attribute {
+    let key = key.into();
+    assert!(
+        !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_'),
+        "invalid data attribute key: '{key}'"
+    );
+    Attribute::new_unsafe_name(format!("data-{key}"), value)
+}
+
 /// `lang` attribute (usually on `html` element)
 pub fn lang(lang: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("lang", lang)
 }
 
+/// Text direction, for the `dir` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dir {
+    /// `ltr` (left to right)
+    Ltr,
+    /// `rtl` (right to left)
+    Rtl,
+    /// `auto` (let the browser decide based on the content)
+    Auto,
+}
+
+impl From<Dir> for Cow<'static, str> {
+    fn from(value: Dir) -> Self {
+        match value {
+            Dir::Ltr => "ltr",
+            Dir::Rtl => "rtl",
+            Dir::Auto => "auto",
+        }
+        .into()
+    }
+}
+
+/// `dir` attribute
+///
+/// It accepts either a [`Dir`] or an arbitrary string.
+pub fn dir(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("dir", value)
+}
+
 /// Represent an anchor target
 #[derive(Debug, Clone)]
 pub enum AnchorTarget {
@@ -106,11 +221,88 @@ pub fn href(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("href", value)
 }
 
+/// `hreflang` attribute (language of the linked resource, for `<a>` and `<link>`)
+pub fn hreflang(code: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("hreflang", code)
+}
+
+/// Represent a referrer policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferrerPolicy {
+    /// `no-referrer`
+    NoReferrer,
+    /// `no-referrer-when-downgrade`
+    NoReferrerWhenDowngrade,
+    /// `origin`
+    Origin,
+    /// `origin-when-cross-origin`
+    OriginWhenCrossOrigin,
+    /// `same-origin`
+    SameOrigin,
+    /// `strict-origin`
+    StrictOrigin,
+    /// `strict-origin-when-cross-origin`
+    StrictOriginWhenCrossOrigin,
+    /// `unsafe-url`
+    UnsafeUrl,
+}
+
+/// `referrerpolicy` attribute (for `<a>`, `<img>`, `<iframe>`, `<link>` and `<script>`)
+pub fn referrerpolicy(policy: ReferrerPolicy) -> Attribute {
+    Attribute::new(
+        "referrerpolicy",
+        match policy {
+            ReferrerPolicy::NoReferrer => "no-referrer",
+            ReferrerPolicy::NoReferrerWhenDowngrade => "no-referrer-when-downgrade",
+            ReferrerPolicy::Origin => "origin",
+            ReferrerPolicy::OriginWhenCrossOrigin => "origin-when-cross-origin",
+            ReferrerPolicy::SameOrigin => "same-origin",
+            ReferrerPolicy::StrictOrigin => "strict-origin",
+            ReferrerPolicy::StrictOriginWhenCrossOrigin => "strict-origin-when-cross-origin",
+            ReferrerPolicy::UnsafeUrl => "unsafe-url",
+        },
+    )
+}
+
+/// Loading strategy of an `<img>` or `<iframe>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Loading {
+    /// `lazy`
+    Lazy,
+    /// `eager`
+    Eager,
+    /// `auto`
+    Auto,
+}
+
+impl From<Loading> for Cow<'static, str> {
+    fn from(value: Loading) -> Self {
+        match value {
+            Loading::Lazy => "lazy",
+            Loading::Eager => "eager",
+            Loading::Auto => "auto",
+        }
+        .into()
+    }
+}
+
+/// `loading` attribute (for `<img>` and `<iframe>`)
+///
+/// It accepts either a [`Loading`] or an arbitrary string.
+pub fn loading(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("loading", value)
+}
+
 /// `rel` attribute
 pub fn rel(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("rel", value)
 }
 
+/// `as` attribute (type of resource for `<link rel="preload">`, such as `"script"`, `"style"` or `"font"`)
+pub fn as_(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("as", value)
+}
+
 /// `src` attribute
 pub fn src(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("src", value)
@@ -141,6 +333,36 @@ pub fn height_int(value: i32) -> Attribute {
     Attribute::new_int("height", value)
 }
 
+/// `media` attribute (for `<link>`, `<source>` and `<style>`)
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::{href, media, rel}, elt::link};
+/// assert_eq!(
+///   link([rel("stylesheet"), media("(max-width: 768px)"), href("/mobile.css")]).to_string(),
+///   r#"<link rel="stylesheet" media="(max-width: 768px)" href="/mobile.css">"#,
+/// );
+/// ```
+pub fn media(query: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("media", query)
+}
+
+/// `media="(min-width: {px}px)"`
+pub fn media_min_width(px: u32) -> Attribute {
+    media(format!("(min-width: {px}px)"))
+}
+
+/// `media="(max-width: {px}px)"`
+pub fn media_max_width(px: u32) -> Attribute {
+    media(format!("(max-width: {px}px)"))
+}
+
+/// `media="(prefers-color-scheme: dark)"`
+pub fn media_prefers_dark() -> Attribute {
+    media("(prefers-color-scheme: dark)")
+}
+
 /// `style` attribute
 pub fn style(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("style", value)
@@ -151,11 +373,70 @@ pub fn cols(value: i32) -> Attribute {
     Attribute::new_int("cols", value)
 }
 
+/// `cols` attribute using a `u32` value
+pub fn cols_u32(value: u32) -> Attribute {
+    Attribute::new_value("cols", value)
+}
+
 /// `rows` attribute
 pub fn rows(value: i32) -> Attribute {
     Attribute::new_int("rows", value)
 }
 
+/// `rows` attribute using a `u32` value
+pub fn rows_u32(value: u32) -> Attribute {
+    Attribute::new_value("rows", value)
+}
+
+/// `span` attribute (number of columns spanned by a `<col>` or `<colgroup>`)
+///
+/// Named `span_attr` so that it does not conflict with [`crate::elt::span`].
+pub fn span_attr(value: u32) -> Attribute {
+    Attribute::new_value("span", value)
+}
+
+/// `colspan` attribute (number of columns spanned by a table cell)
+pub fn colspan(value: u32) -> Attribute {
+    Attribute::new_value("colspan", value)
+}
+
+/// `rowspan` attribute (number of rows spanned by a table cell)
+pub fn rowspan(value: u32) -> Attribute {
+    Attribute::new_value("rowspan", value)
+}
+
+/// Cells a table header applies to, for the `scope` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    /// `col`
+    Col,
+    /// `row`
+    Row,
+    /// `colgroup`
+    Colgroup,
+    /// `rowgroup`
+    Rowgroup,
+}
+
+impl From<Scope> for Cow<'static, str> {
+    fn from(value: Scope) -> Self {
+        match value {
+            Scope::Col => "col",
+            Scope::Row => "row",
+            Scope::Colgroup => "colgroup",
+            Scope::Rowgroup => "rowgroup",
+        }
+        .into()
+    }
+}
+
+/// `scope` attribute (for `<th>`)
+///
+/// It accepts either a [`Scope`] or an arbitrary string.
+pub fn scope(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("scope", value)
+}
+
 /// `type` attribute
 pub fn type_(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("type", value)
@@ -231,21 +512,48 @@ pub fn type_submit() -> Attribute {
     Attribute::new("type", "submit")
 }
 
-/// `integrity` attribute
+/// `integrity` attribute (Subresource Integrity hash)
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::{crossorigin_anonymous, integrity, src}, elt::script_empty};
+/// let script = script_empty([
+///     src("https://cdn.example.com/lib.js"),
+///     integrity("sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC"),
+///     crossorigin_anonymous(),
+/// ]);
+/// ```
 pub fn integrity(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("integrity", value)
 }
 
+/// `nonce` attribute (for `<script>` and `<style>` with a nonce-based Content Security Policy)
+pub fn nonce(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("nonce", value)
+}
+
 /// `defer` attribute
+///
+/// The script is executed after the document has been parsed.
 pub fn defer() -> Attribute {
     Attribute::new_flag("defer")
 }
 
 /// `async` attribute
+///
+/// The script is executed as soon as it is available, without blocking the parser.
 pub fn async_() -> Attribute {
     Attribute::new_flag("async")
 }
 
+/// `crossorigin` attribute
+///
+/// See also [`crossorigin_anonymous`] and [`crossorigin_use_credentials`]
+pub fn crossorigin(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("crossorigin", value)
+}
+
 /// `crossorigin="anonymous"`
 pub fn crossorigin_anonymous() -> Attribute {
     Attribute::new("crossorigin", "anonymous")
@@ -292,6 +600,11 @@ pub fn content(content: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("content", content)
 }
 
+/// `property` attribute (used by `<meta>` for the [Open Graph protocol](https://ogp.me))
+pub fn property(property: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("property", property)
+}
+
 /// `action` attribute
 pub fn action(action: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("action", action)
@@ -321,16 +634,210 @@ pub fn value(value: impl Into<Cow<'static, str>>) -> Attribute {
 pub fn required() -> Attribute {
     Attribute::new_flag("required")
 }
+
+/// `selected` attribute (for `<option>`)
+pub fn selected() -> Attribute {
+    Attribute::new_flag("selected")
+}
+
+/// `checked` attribute (for checkbox and radio `<input>`)
+pub fn checked() -> Attribute {
+    Attribute::new_flag("checked")
+}
 /// `autofocus` attribute
 pub fn autofocus() -> Attribute {
     Attribute::new_flag("autofocus")
 }
 
 /// `autocomplete` attribute
+///
+/// It accepts either an [`Autocomplete`] token or an arbitrary string.
 pub fn autocomplete(type_: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("autocomplete", type_)
 }
 
+/// Autofill token of the `autocomplete` attribute
+///
+/// See [`autocomplete`] and [`autocomplete_tokens`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Autocomplete {
+    /// `off`
+    Off,
+    /// `on`
+    On,
+    /// `shipping`
+    Shipping,
+    /// `billing`
+    Billing,
+    /// `home`
+    Home,
+    /// `work`
+    Work,
+    /// `mobile`
+    Mobile,
+    /// `name`
+    Name,
+    /// `honorific-prefix`
+    HonorificPrefix,
+    /// `given-name`
+    GivenName,
+    /// `additional-name`
+    AdditionalName,
+    /// `family-name`
+    FamilyName,
+    /// `honorific-suffix`
+    HonorificSuffix,
+    /// `nickname`
+    Nickname,
+    /// `email`
+    Email,
+    /// `username`
+    Username,
+    /// `new-password`
+    NewPassword,
+    /// `current-password`
+    CurrentPassword,
+    /// `one-time-code`
+    OneTimeCode,
+    /// `organization-title`
+    OrganizationTitle,
+    /// `organization`
+    Organization,
+    /// `street-address`
+    StreetAddress,
+    /// `address-line1`
+    AddressLine1,
+    /// `address-line2`
+    AddressLine2,
+    /// `address-line3`
+    AddressLine3,
+    /// `address-level1`
+    AddressLevel1,
+    /// `address-level2`
+    AddressLevel2,
+    /// `country`
+    Country,
+    /// `country-name`
+    CountryName,
+    /// `postal-code`
+    PostalCode,
+    /// `cc-name`
+    CcName,
+    /// `cc-number`
+    CcNumber,
+    /// `cc-exp`
+    CcExp,
+    /// `cc-exp-month`
+    CcExpMonth,
+    /// `cc-exp-year`
+    CcExpYear,
+    /// `cc-csc`
+    CcCsc,
+    /// `cc-type`
+    CcType,
+    /// `transaction-currency`
+    TransactionCurrency,
+    /// `transaction-amount`
+    TransactionAmount,
+    /// `language`
+    Language,
+    /// `bday`
+    Bday,
+    /// `sex`
+    Sex,
+    /// `tel`
+    Tel,
+    /// `url`
+    Url,
+    /// `photo`
+    Photo,
+    /// `section-{name}` (groups fields of the same form)
+    Section(Cow<'static, str>),
+    /// Any other token
+    Custom(Cow<'static, str>),
+}
+
+impl From<Autocomplete> for Cow<'static, str> {
+    fn from(value: Autocomplete) -> Self {
+        match value {
+            Autocomplete::Off => "off".into(),
+            Autocomplete::On => "on".into(),
+            Autocomplete::Shipping => "shipping".into(),
+            Autocomplete::Billing => "billing".into(),
+            Autocomplete::Home => "home".into(),
+            Autocomplete::Work => "work".into(),
+            Autocomplete::Mobile => "mobile".into(),
+            Autocomplete::Name => "name".into(),
+            Autocomplete::HonorificPrefix => "honorific-prefix".into(),
+            Autocomplete::GivenName => "given-name".into(),
+            Autocomplete::AdditionalName => "additional-name".into(),
+            Autocomplete::FamilyName => "family-name".into(),
+            Autocomplete::HonorificSuffix => "honorific-suffix".into(),
+            Autocomplete::Nickname => "nickname".into(),
+            Autocomplete::Email => "email".into(),
+            Autocomplete::Username => "username".into(),
+            Autocomplete::NewPassword => "new-password".into(),
+            Autocomplete::CurrentPassword => "current-password".into(),
+            Autocomplete::OneTimeCode => "one-time-code".into(),
+            Autocomplete::OrganizationTitle => "organization-title".into(),
+            Autocomplete::Organization => "organization".into(),
+            Autocomplete::StreetAddress => "street-address".into(),
+            Autocomplete::AddressLine1 => "address-line1".into(),
+            Autocomplete::AddressLine2 => "address-line2".into(),
+            Autocomplete::AddressLine3 => "address-line3".into(),
+            Autocomplete::AddressLevel1 => "address-level1".into(),
+            Autocomplete::AddressLevel2 => "address-level2".into(),
+            Autocomplete::Country => "country".into(),
+            Autocomplete::CountryName => "country-name".into(),
+            Autocomplete::PostalCode => "postal-code".into(),
+            Autocomplete::CcName => "cc-name".into(),
+            Autocomplete::CcNumber => "cc-number".into(),
+            Autocomplete::CcExp => "cc-exp".into(),
+            Autocomplete::CcExpMonth => "cc-exp-month".into(),
+            Autocomplete::CcExpYear => "cc-exp-year".into(),
+            Autocomplete::CcCsc => "cc-csc".into(),
+            Autocomplete::CcType => "cc-type".into(),
+            Autocomplete::TransactionCurrency => "transaction-currency".into(),
+            Autocomplete::TransactionAmount => "transaction-amount".into(),
+            Autocomplete::Language => "language".into(),
+            Autocomplete::Bday => "bday".into(),
+            Autocomplete::Sex => "sex".into(),
+            Autocomplete::Tel => "tel".into(),
+            Autocomplete::Url => "url".into(),
+            Autocomplete::Photo => "photo".into(),
+            Autocomplete::Section(name) => format!("section-{name}").into(),
+            Autocomplete::Custom(token) => token,
+        }
+    }
+}
+
+/// `autocomplete` attribute with a space-separated list of tokens
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::attr::{autocomplete_tokens, Autocomplete};
+/// assert_eq!(
+///     autocomplete_tokens([
+///         Autocomplete::Section("blue".into()),
+///         Autocomplete::Shipping,
+///         Autocomplete::Email,
+///     ])
+///     .to_string(),
+///     r#"autocomplete="section-blue shipping email""#,
+/// );
+/// ```
+pub fn autocomplete_tokens(tokens: impl IntoIterator<Item = Autocomplete>) -> Attribute {
+    let mut value = String::new();
+    for token in tokens {
+        if !value.is_empty() {
+            value.push(' ');
+        }
+        value.push_str(&Cow::from(token));
+    }
+    Attribute::new("autocomplete", value)
+}
+
 /// `autocomplete="on"` attribute
 pub fn autocomplete_on() -> Attribute {
     Attribute::new("autocomplete", "on")
@@ -346,7 +853,12 @@ pub fn disabled() -> Attribute {
     Attribute::new_flag("disabled")
 }
 
-/// `pattern` attribute
+/// `hidden` attribute
+pub fn hidden() -> Attribute {
+    Attribute::new_flag("hidden")
+}
+
+/// `pattern` attribute (regular expression that the value of an `<input>` must match)
 pub fn pattern(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("pattern", value)
 }
@@ -361,11 +873,21 @@ pub fn max(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("max", value)
 }
 
+/// `step` attribute
+pub fn step(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("step", value)
+}
+
 /// `minlength` attribute
 pub fn minlength(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("minlength", value)
 }
 
+/// `minlength` attribute using a `usize` value
+pub fn minlength_usize(value: usize) -> Attribute {
+    Attribute::new_value("minlength", value)
+}
+
 /// `minlength` attribute using a `u16` value
 pub fn minlength_u16(value: u16) -> Attribute {
     Attribute::new_int("minlength", value.into())
@@ -376,11 +898,60 @@ pub fn maxlength(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("maxlength", value)
 }
 
+/// `maxlength` attribute using a `usize` value
+pub fn maxlength_usize(value: usize) -> Attribute {
+    Attribute::new_value("maxlength", value)
+}
+
 /// `maxlength` attribute using a `u16` value
 pub fn maxlength_u16(value: u16) -> Attribute {
     Attribute::new_int("maxlength", value.into())
 }
 
+/// Virtual keyboard hint of the `inputmode` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputMode {
+    /// `none`
+    None,
+    /// `text`
+    Text,
+    /// `decimal`
+    Decimal,
+    /// `numeric`
+    Numeric,
+    /// `tel`
+    Tel,
+    /// `search`
+    Search,
+    /// `email`
+    Email,
+    /// `url`
+    Url,
+}
+
+impl From<InputMode> for Cow<'static, str> {
+    fn from(value: InputMode) -> Self {
+        match value {
+            InputMode::None => "none",
+            InputMode::Text => "text",
+            InputMode::Decimal => "decimal",
+            InputMode::Numeric => "numeric",
+            InputMode::Tel => "tel",
+            InputMode::Search => "search",
+            InputMode::Email => "email",
+            InputMode::Url => "url",
+        }
+        .into()
+    }
+}
+
+/// `inputmode` attribute
+///
+/// It accepts either an [`InputMode`] or an arbitrary string.
+pub fn inputmode(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("inputmode", value)
+}
+
 /// `multiple` attribute
 pub fn multiple() -> Attribute {
     Attribute::new_flag("multiple")
@@ -395,3 +966,602 @@ pub fn placeholder(value: impl Into<Cow<'static, str>>) -> Attribute {
 pub fn role_str(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("role", value)
 }
+
+/// `role` attribute
+///
+/// It accepts either a [`Role`] or an arbitrary string.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::attr::{role, Role};
+/// assert_eq!(role(Role::Navigation).to_string(), r#"role="navigation""#);
+/// assert_eq!(role("doc-toc").to_string(), r#"role="doc-toc""#);
+/// ```
+pub fn role(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("role", value)
+}
+
+/// ARIA role
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// `alert`
+    Alert,
+    /// `alertdialog`
+    AlertDialog,
+    /// `article`
+    Article,
+    /// `banner`
+    Banner,
+    /// `button`
+    Button,
+    /// `cell`
+    Cell,
+    /// `checkbox`
+    Checkbox,
+    /// `columnheader`
+    ColumnHeader,
+    /// `combobox`
+    Combobox,
+    /// `complementary`
+    Complementary,
+    /// `contentinfo`
+    ContentInfo,
+    /// `dialog`
+    Dialog,
+    /// `document`
+    Document,
+    /// `feed`
+    Feed,
+    /// `figure`
+    Figure,
+    /// `form`
+    Form,
+    /// `grid`
+    Grid,
+    /// `gridcell`
+    GridCell,
+    /// `group`
+    Group,
+    /// `heading`
+    Heading,
+    /// `img`
+    Img,
+    /// `link`
+    Link,
+    /// `list`
+    List,
+    /// `listbox`
+    ListBox,
+    /// `listitem`
+    ListItem,
+    /// `log`
+    Log,
+    /// `main`
+    Main,
+    /// `marquee`
+    Marquee,
+    /// `math`
+    Math,
+    /// `menu`
+    Menu,
+    /// `menubar`
+    MenuBar,
+    /// `menuitem`
+    MenuItem,
+    /// `menuitemcheckbox`
+    MenuItemCheckbox,
+    /// `menuitemradio`
+    MenuItemRadio,
+    /// `meter`
+    Meter,
+    /// `navigation`
+    Navigation,
+    /// `none`
+    None,
+    /// `note`
+    Note,
+    /// `option`
+    Option,
+    /// `presentation`
+    Presentation,
+    /// `progressbar`
+    ProgressBar,
+    /// `radio`
+    Radio,
+    /// `radiogroup`
+    RadioGroup,
+    /// `region`
+    Region,
+    /// `row`
+    Row,
+    /// `rowgroup`
+    RowGroup,
+    /// `rowheader`
+    RowHeader,
+    /// `scrollbar`
+    ScrollBar,
+    /// `search`
+    Search,
+    /// `searchbox`
+    SearchBox,
+    /// `separator`
+    Separator,
+    /// `slider`
+    Slider,
+    /// `spinbutton`
+    SpinButton,
+    /// `status`
+    Status,
+    /// `switch`
+    Switch,
+    /// `tab`
+    Tab,
+    /// `table`
+    Table,
+    /// `tablist`
+    TabList,
+    /// `tabpanel`
+    TabPanel,
+    /// `term`
+    Term,
+    /// `textbox`
+    TextBox,
+    /// `timer`
+    Timer,
+    /// `toolbar`
+    Toolbar,
+    /// `tooltip`
+    Tooltip,
+    /// `tree`
+    Tree,
+    /// `treegrid`
+    TreeGrid,
+    /// `treeitem`
+    TreeItem,
+}
+
+impl From<Role> for Cow<'static, str> {
+    fn from(value: Role) -> Self {
+        match value {
+            Role::Alert => "alert",
+            Role::AlertDialog => "alertdialog",
+            Role::Article => "article",
+            Role::Banner => "banner",
+            Role::Button => "button",
+            Role::Cell => "cell",
+            Role::Checkbox => "checkbox",
+            Role::ColumnHeader => "columnheader",
+            Role::Combobox => "combobox",
+            Role::Complementary => "complementary",
+            Role::ContentInfo => "contentinfo",
+            Role::Dialog => "dialog",
+            Role::Document => "document",
+            Role::Feed => "feed",
+            Role::Figure => "figure",
+            Role::Form => "form",
+            Role::Grid => "grid",
+            Role::GridCell => "gridcell",
+            Role::Group => "group",
+            Role::Heading => "heading",
+            Role::Img => "img",
+            Role::Link => "link",
+            Role::List => "list",
+            Role::ListBox => "listbox",
+            Role::ListItem => "listitem",
+            Role::Log => "log",
+            Role::Main => "main",
+            Role::Marquee => "marquee",
+            Role::Math => "math",
+            Role::Menu => "menu",
+            Role::MenuBar => "menubar",
+            Role::MenuItem => "menuitem",
+            Role::MenuItemCheckbox => "menuitemcheckbox",
+            Role::MenuItemRadio => "menuitemradio",
+            Role::Meter => "meter",
+            Role::Navigation => "navigation",
+            Role::None => "none",
+            Role::Note => "note",
+            Role::Option => "option",
+            Role::Presentation => "presentation",
+            Role::ProgressBar => "progressbar",
+            Role::Radio => "radio",
+            Role::RadioGroup => "radiogroup",
+            Role::Region => "region",
+            Role::Row => "row",
+            Role::RowGroup => "rowgroup",
+            Role::RowHeader => "rowheader",
+            Role::ScrollBar => "scrollbar",
+            Role::Search => "search",
+            Role::SearchBox => "searchbox",
+            Role::Separator => "separator",
+            Role::Slider => "slider",
+            Role::SpinButton => "spinbutton",
+            Role::Status => "status",
+            Role::Switch => "switch",
+            Role::Tab => "tab",
+            Role::Table => "table",
+            Role::TabList => "tablist",
+            Role::TabPanel => "tabpanel",
+            Role::Term => "term",
+            Role::TextBox => "textbox",
+            Role::Timer => "timer",
+            Role::Toolbar => "toolbar",
+            Role::Tooltip => "tooltip",
+            Role::Tree => "tree",
+            Role::TreeGrid => "treegrid",
+            Role::TreeItem => "treeitem",
+        }
+        .into()
+    }
+}
+
+/// `itemscope` attribute
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::{itemprop, itemscope, itemtype}, elt::{div, span}};
+/// let product = div(
+///     [itemscope(), itemtype("https://schema.org/Product")],
+///     [
+///         span([itemprop("name")], ["Executive Anvil".into()]),
+///         span([itemprop("price")], ["119.99".into()]),
+///     ],
+/// );
+///
+/// assert_eq!(
+///     product.to_string(),
+///     r#"<div itemscope itemtype="https://schema.org/Product"><span itemprop="name">Executive Anvil</span><span itemprop="price">119.99</span></div>"#,
+/// );
+/// ```
+pub fn itemscope() -> Attribute {
+    Attribute::new_flag("itemscope")
+}
+
+/// `itemprop` attribute
+pub fn itemprop(name: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("itemprop", name)
+}
+
+/// `itemtype` attribute
+pub fn itemtype(url: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("itemtype", url)
+}
+
+/// `itemid` attribute
+pub fn itemid(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("itemid", value)
+}
+
+/// `aria-*` attribute
+///
+/// The name is prefixed with `aria-`.
+///
+/// This is an escape hatch for the ARIA attributes that don't have a dedicated function.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::attr::aria;
+/// assert_eq!(
+///   aria("colcount", 12).to_string(),
+///   r#"aria-colcount="12""#,
+/// );
+/// ```
+pub fn aria(name: &'static str, value: impl Into<AttributeValue>) -> Attribute {
+    crate::assert_valid_attribute_name(name);
+    Attribute::new_owned_name(format!("aria-{name}"), value)
+}
+
+/// `aria-label` attribute
+pub fn aria_label(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-label", value)
+}
+
+/// `aria-labelledby` attribute
+pub fn aria_labelledby(ids: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-labelledby", ids)
+}
+
+/// `aria-describedby` attribute
+pub fn aria_describedby(ids: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-describedby", ids)
+}
+
+/// `aria-controls` attribute
+pub fn aria_controls(ids: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-controls", ids)
+}
+
+/// `aria-current` attribute (e.g. `"page"`, `"step"`, `"true"`)
+pub fn aria_current(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-current", value)
+}
+
+/// `aria-haspopup` attribute (e.g. `"menu"`, `"dialog"`, `"true"`)
+pub fn aria_haspopup(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-haspopup", value)
+}
+
+/// Politeness setting of a live region
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AriaLive {
+    /// `off`
+    Off,
+    /// `polite`
+    Polite,
+    /// `assertive`
+    Assertive,
+}
+
+/// `aria-live` attribute
+pub fn aria_live(value: AriaLive) -> Attribute {
+    Attribute::new(
+        "aria-live",
+        match value {
+            AriaLive::Off => "off",
+            AriaLive::Polite => "polite",
+            AriaLive::Assertive => "assertive",
+        },
+    )
+}
+
+/// `aria-atomic` attribute
+pub fn aria_atomic(value: bool) -> Attribute {
+    Attribute::new_value("aria-atomic", value)
+}
+
+/// `aria-busy` attribute
+pub fn aria_busy(value: bool) -> Attribute {
+    Attribute::new_value("aria-busy", value)
+}
+
+/// `aria-hidden` attribute
+pub fn aria_hidden(value: bool) -> Attribute {
+    Attribute::new_value("aria-hidden", value)
+}
+
+/// `aria-expanded` attribute
+pub fn aria_expanded(value: bool) -> Attribute {
+    Attribute::new_value("aria-expanded", value)
+}
+
+/// `aria-selected` attribute
+pub fn aria_selected(value: bool) -> Attribute {
+    Attribute::new_value("aria-selected", value)
+}
+
+/// `aria-checked` attribute
+///
+/// Use [`aria`] for the `"mixed"` value
+pub fn aria_checked(value: bool) -> Attribute {
+    Attribute::new_value("aria-checked", value)
+}
+
+/// `aria-pressed` attribute
+///
+/// Use [`aria`] for the `"mixed"` value
+pub fn aria_pressed(value: bool) -> Attribute {
+    Attribute::new_value("aria-pressed", value)
+}
+
+/// `aria-disabled` attribute
+pub fn aria_disabled(value: bool) -> Attribute {
+    Attribute::new_value("aria-disabled", value)
+}
+
+/// `aria-invalid` attribute
+pub fn aria_invalid(value: bool) -> Attribute {
+    Attribute::new_value("aria-invalid", value)
+}
+
+/// `aria-modal` attribute
+pub fn aria_modal(value: bool) -> Attribute {
+    Attribute::new_value("aria-modal", value)
+}
+
+/// `aria-required` attribute
+pub fn aria_required(value: bool) -> Attribute {
+    Attribute::new_value("aria-required", value)
+}
+
+/// `aria-level` attribute
+pub fn aria_level(value: u32) -> Attribute {
+    Attribute::new_value("aria-level", value)
+}
+
+/// `aria-valuenow` attribute
+pub fn aria_valuenow(value: f64) -> Attribute {
+    Attribute::new_value("aria-valuenow", value)
+}
+
+/// `aria-valuemin` attribute
+pub fn aria_valuemin(value: f64) -> Attribute {
+    Attribute::new_value("aria-valuemin", value)
+}
+
+/// `aria-valuemax` attribute
+pub fn aria_valuemax(value: f64) -> Attribute {
+    Attribute::new_value("aria-valuemax", value)
+}
+
+/// `srcset` attribute
+///
+/// See [`SrcSet`] to build the value from a list of image candidates.
+pub fn srcset(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("srcset", value)
+}
+
+/// `sizes` attribute (for `<img>`, `<source>` and `<link rel="icon">`)
+pub fn sizes(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("sizes", value)
+}
+
+/// `on{event}` event handler attribute
+///
+/// The script is run by the browser as JavaScript when the event fires.
+/// It is escaped as any other attribute value, which prevents breaking out of the attribute,
+/// but it is still raw JavaScript: never build it from untrusted input.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::on, elt::button};
+/// assert_eq!(
+///   button([on("click", "alert('hello')")], ["Greet".into()]).to_string(),
+///   r#"<button onclick="alert('hello')">Greet</button>"#,
+/// );
+/// ```
+pub fn on(event: &'static str, script: impl Into<Cow<'static, str>>) -> Attribute {
+    crate::assert_valid_attribute_name(event);
+    Attribute::new_owned_name(format!("on{event}"), script.into())
+}
+
+/// `onclick` event handler attribute (JavaScript run when the element is clicked, see [`on`])
+pub fn onclick(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onclick", script)
+}
+
+/// `ondblclick` event handler attribute (JavaScript run when the element is double-clicked, see [`on`])
+pub fn ondblclick(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("ondblclick", script)
+}
+
+/// `onsubmit` event handler attribute (JavaScript run when a form is submitted, see [`on`])
+pub fn onsubmit(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onsubmit", script)
+}
+
+/// `onreset` event handler attribute (JavaScript run when a form is reset, see [`on`])
+pub fn onreset(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onreset", script)
+}
+
+/// `oninput` event handler attribute (JavaScript run when the value of an input changes, see [`on`])
+pub fn oninput(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("oninput", script)
+}
+
+/// `onchange` event handler attribute (JavaScript run when the value of an input is committed, see [`on`])
+pub fn onchange(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onchange", script)
+}
+
+/// `onkeydown` event handler attribute (JavaScript run when a key is pressed, see [`on`])
+pub fn onkeydown(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onkeydown", script)
+}
+
+/// `onkeyup` event handler attribute (JavaScript run when a key is released, see [`on`])
+pub fn onkeyup(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onkeyup", script)
+}
+
+/// `onfocus` event handler attribute (JavaScript run when the element receives focus, see [`on`])
+pub fn onfocus(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onfocus", script)
+}
+
+/// `onblur` event handler attribute (JavaScript run when the element loses focus, see [`on`])
+pub fn onblur(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onblur", script)
+}
+
+/// `onmouseover` event handler attribute (JavaScript run when the pointer enters the element, see [`on`])
+pub fn onmouseover(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onmouseover", script)
+}
+
+/// `onmouseout` event handler attribute (JavaScript run when the pointer leaves the element, see [`on`])
+pub fn onmouseout(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onmouseout", script)
+}
+
+/// `onload` event handler attribute (JavaScript run when the resource has loaded, see [`on`])
+pub fn onload(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onload", script)
+}
+
+/// `onerror` event handler attribute (JavaScript run when the resource failed to load, see [`on`])
+pub fn onerror(script: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("onerror", script)
+}
+
+/// Builder for the value of the `srcset` attribute
+///
+/// Each image candidate has either a width descriptor (`320w`) or a pixel density descriptor (`2x`).
+/// Mixing width and density descriptors in the same set is not valid HTML (and panics in debug builds).
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{Attribute, attr::SrcSet};
+/// let srcset: Attribute = SrcSet::new()
+///     .width("img-320.png", 320)
+///     .width("img-640.png", 640)
+///     .into();
+///
+/// assert_eq!(
+///     srcset.to_string(),
+///     r#"srcset="img-320.png 320w, img-640.png 640w""#,
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SrcSet(Vec<(Cow<'static, str>, SrcSetDescriptor)>);
+
+#[derive(Debug, Clone, Copy)]
+enum SrcSetDescriptor {
+    Width(u32),
+    Density(f64),
+}
+
+impl SrcSet {
+    /// Create an empty set of image candidates
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an image candidate with a width descriptor (`{url} {width}w`)
+    pub fn width(mut self, url: impl Into<Cow<'static, str>>, width: u32) -> Self {
+        debug_assert!(
+            self.0
+                .iter()
+                .all(|(_, d)| matches!(d, SrcSetDescriptor::Width(_))),
+            "cannot mix width and density descriptors in a srcset"
+        );
+        self.0.push((url.into(), SrcSetDescriptor::Width(width)));
+        self
+    }
+
+    /// Add an image candidate with a pixel density descriptor (`{url} {density}x`)
+    pub fn density(mut self, url: impl Into<Cow<'static, str>>, density: f64) -> Self {
+        debug_assert!(
+            self.0
+                .iter()
+                .all(|(_, d)| matches!(d, SrcSetDescriptor::Density(_))),
+            "cannot mix width and density descriptors in a srcset"
+        );
+        self.0
+            .push((url.into(), SrcSetDescriptor::Density(density)));
+        self
+    }
+}
+
+impl From<SrcSet> for Attribute {
+    fn from(SrcSet(candidates): SrcSet) -> Self {
+        let mut value = String::new();
+        for (i, (url, descriptor)) in candidates.iter().enumerate() {
+            if i > 0 {
+                value.push_str(", ");
+            }
+            value.push_str(url);
+            match descriptor {
+                SrcSetDescriptor::Width(width) => write!(value, " {width}w"),
+                SrcSetDescriptor::Density(density) => write!(value, " {density}x"),
+            }
+            .unwrap();
+        }
+        srcset(value)
+    }
+}
@@ -0,0 +1,75 @@
+//! Attributes for [Alpine.js](https://alpinejs.dev)
+//!
+//! The values are JavaScript expressions. They are escaped like any other attribute value.
+//!
+//! ## Example
+//!
+//! ```
+//! use fun_html::{attr::alpine::{x_data, x_on, x_show}, elt::{button, div, span}};
+//!
+//! let toggle = div(
+//!     [x_data("{ open: false }")],
+//!     [
+//!         button([x_on("click", "open = !open")], ["Toggle".into()]),
+//!         span([x_show("open")], ["Hello".into()]),
+//!     ],
+//! );
+//!
+//! assert_eq!(
+//!     toggle.to_string(),
+//!     r#"<div x-data="{ open: false }"><button x-on:click="open = !open">Toggle</button><span x-show="open">Hello</span></div>"#,
+//! );
+//! ```
+
+use alloc::{borrow::Cow, format};
+
+use crate::Attribute;
+
+/// `x-data` attribute
+pub fn x_data(js: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("x-data", js)
+}
+
+/// `x-show` attribute
+pub fn x_show(expr: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("x-show", expr)
+}
+
+/// `x-if` attribute (to use on a `<template>` element)
+pub fn x_if(expr: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("x-if", expr)
+}
+
+/// `x-for` attribute (to use on a `<template>` element)
+pub fn x_for(expr: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("x-for", expr)
+}
+
+/// `x-model` attribute
+pub fn x_model(name: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("x-model", name)
+}
+
+/// `x-text` attribute
+pub fn x_text(expr: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("x-text", expr)
+}
+
+/// `x-html` attribute
+pub fn x_html(expr: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("x-html", expr)
+}
+
+/// `x-on:{event}` attribute
+///
+/// The event may include modifiers (e.g. `"click.prevent"` or `"keyup.enter"`)
+pub fn x_on(event: &'static str, expr: impl Into<Cow<'static, str>>) -> Attribute {
+    crate::assert_valid_attribute_name(event);
+    Attribute::new_owned_name(format!("x-on:{event}"), expr.into())
+}
+
+/// `x-bind:{attribute}` attribute
+pub fn x_bind(attribute: &'static str, expr: impl Into<Cow<'static, str>>) -> Attribute {
+    crate::assert_valid_attribute_name(attribute);
+    Attribute::new_owned_name(format!("x-bind:{attribute}"), expr.into())
+}
@@ -30,6 +30,13 @@ pub fn none() -> Element {
     Element(ElementInner::None)
 }
 
+/// Group sibling elements without any surrounding tag
+///
+/// See [`crate::elt::fragment`]
+pub fn fragment(children: impl IntoIterator<Item = Element>) -> Element {
+    crate::elt::fragment(children)
+}
+
 /// `<div>`
 pub fn div(children: impl IntoIterator<Item = Element>) -> Element {
     Element::new("div", [], children)
@@ -71,6 +78,11 @@ pub fn meta_color_scheme(scheme: impl Into<Cow<'static, str>>) -> Element {
     meta([name("color-scheme"), content(scheme)])
 }
 
+/// `<meta name="description" content="{text}">`
+pub fn meta_description(text: impl Into<Cow<'static, str>>) -> Element {
+    meta([name("description"), content(text)])
+}
+
 /// `<link>`
 pub fn link(attributes: impl IntoIterator<Item = Attribute>) -> Element {
     Element::new_void("link", attributes)
@@ -191,6 +203,16 @@ pub fn tfoot(children: impl IntoIterator<Item = Element>) -> Element {
     Element::new("tfoot", [], children)
 }
 
+/// `<table>` with a header row and a row for each item of `rows`
+///
+/// See [`crate::elt::table_from_rows`]
+pub fn table_from_rows(
+    headers: impl IntoIterator<Item = Element>,
+    rows: impl IntoIterator<Item = impl IntoIterator<Item = Element>>,
+) -> Element {
+    crate::elt::table_from_rows([], headers, rows)
+}
+
 /// `<section>`
 pub fn section(children: impl IntoIterator<Item = Element>) -> Element {
     Element::new("section", [], children)
@@ -244,6 +266,20 @@ pub fn li(children: impl IntoIterator<Item = Element>) -> Element {
     Element::new("li", [], children)
 }
 
+/// `<ul>` with each item wrapped in a `<li>`
+///
+/// See [`crate::elt::ul_from`]
+pub fn ul_from(items: impl IntoIterator<Item = Element>) -> Element {
+    crate::elt::ul_from([], items)
+}
+
+/// `<ol>` with each item wrapped in a `<li>`
+///
+/// See [`crate::elt::ol_from`]
+pub fn ol_from(items: impl IntoIterator<Item = Element>) -> Element {
+    crate::elt::ol_from([], items)
+}
+
 /// `<form>`
 pub fn form(
     attributes: impl IntoIterator<Item = Attribute>,
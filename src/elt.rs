@@ -4,11 +4,11 @@
 //!
 //! It is also possible to inline raw HTML with [`raw`] and [`raw_unsafe`]
 
-use alloc::{borrow::Cow, string::String};
+use alloc::{borrow::Cow, format, string::String, sync::Arc, vec::Vec};
 
 use crate::{
-    attr::{content, href, name, rel},
-    Attribute, Element, ElementInner,
+    attr::{self, content, for_, href, name, rel},
+    Attribute, Element, ElementInner, LazyElement,
 };
 
 /// Renders nothing. Useful fo conditional rendering.
@@ -29,6 +29,142 @@ pub fn none() -> Element {
     Element(ElementInner::None)
 }
 
+/// Element built by `f` if the condition is `true`, renders nothing otherwise
+///
+/// The closure is not called when the condition is `false`.
+///
+/// # Example
+///
+/// ```
+/// use fun_html::elt::{div, p, show_if, text};
+///
+/// let logged_in = false;
+/// let page = div([], [
+///   show_if(logged_in, || p([], [text("Welcome back!")])),
+///   text("content"),
+/// ]);
+/// assert_eq!(page.to_string(), "<div>content</div>");
+/// ```
+pub fn show_if(condition: bool, f: impl FnOnce() -> Element) -> Element {
+    if condition {
+        f()
+    } else {
+        none()
+    }
+}
+
+/// Element built by `then` if the condition is `true`, and by `otherwise` if it is `false`
+///
+/// Only the closure of the matching branch is called.
+///
+/// # Example
+///
+/// ```
+/// use fun_html::{attr::href, elt::{a, div, show_if_else, text}};
+///
+/// let logged_in = true;
+/// let menu = div([], [show_if_else(
+///   logged_in,
+///   || a([href("/logout")], [text("Log out")]),
+///   || a([href("/login")], [text("Log in")]),
+/// )]);
+/// assert_eq!(menu.to_string(), r#"<div><a href="/logout">Log out</a></div>"#);
+/// ```
+pub fn show_if_else(
+    condition: bool,
+    then: impl FnOnce() -> Element,
+    otherwise: impl FnOnce() -> Element,
+) -> Element {
+    if condition {
+        then()
+    } else {
+        otherwise()
+    }
+}
+
+/// Element built by `f` from the value if it is `Some`, renders nothing if it is `None`
+///
+/// # Example
+///
+/// ```
+/// use fun_html::elt::{li, maybe, text, ul};
+///
+/// let nickname: Option<&str> = Some("Jo");
+/// let email: Option<&str> = None;
+/// let list = ul([], [
+///   maybe(nickname, |name| li([], [text(name)])),
+///   maybe(email, |email| li([], [text(email)])),
+/// ]);
+/// assert_eq!(list.to_string(), "<ul><li>Jo</li></ul>");
+/// ```
+pub fn maybe<T>(option: Option<T>, f: impl FnOnce(T) -> Element) -> Element {
+    option.map(f).unwrap_or_default()
+}
+
+/// Group sibling elements without any surrounding tag
+///
+/// A fragment can be used anywhere an element can, and its children are rendered in order.
+/// This is equivalent to converting an array or a `Vec` of elements with `.into()`.
+///
+/// # Example
+///
+/// ```
+/// use fun_html::{Element, attr, elt::{fragment, input, label, ul, li}};
+///
+/// fn field() -> Element {
+///   fragment([label([], ["Name".into()]), input([attr::name("name")])])
+/// }
+///
+/// assert_eq!(field().to_string(), r#"<label>Name</label><input name="name">"#);
+/// assert_eq!(
+///   ul([], [fragment([li([], ["a".into()]), li([], ["b".into()])])]).to_string(),
+///   "<ul><li>a</li><li>b</li></ul>",
+/// );
+/// ```
+pub fn fragment(children: impl IntoIterator<Item = Element>) -> Element {
+    Element(ElementInner::Multiple(children.into_iter().collect()))
+}
+
+/// Fragment with a clone of `separator` between each item
+///
+/// Nothing is rendered before the first item nor after the last one.
+///
+/// # Example
+///
+/// ```
+/// use fun_html::elt::{a, join, text};
+/// use fun_html::attr::href;
+///
+/// let breadcrumbs = join(
+///   [a([href("/")], [text("Home")]), a([href("/docs")], [text("Docs")]), text("Join")],
+///   text(" › "),
+/// );
+/// assert_eq!(
+///   breadcrumbs.to_string(),
+///   r#"<a href="/">Home</a> › <a href="/docs">Docs</a> › Join"#,
+/// );
+/// ```
+pub fn join(items: impl IntoIterator<Item = Element>, separator: Element) -> Element {
+    join_with(items, || separator.clone())
+}
+
+/// Fragment with a separator built by `separator` between each item
+///
+/// See [`join`]
+pub fn join_with(
+    items: impl IntoIterator<Item = Element>,
+    mut separator: impl FnMut() -> Element,
+) -> Element {
+    let mut children = Vec::new();
+    for (index, item) in items.into_iter().enumerate() {
+        if index > 0 {
+            children.push(separator());
+        }
+        children.push(item);
+    }
+    fragment(children)
+}
+
 /// `<div>`
 pub fn div(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -37,6 +173,43 @@ pub fn div(
     Element::new("div", attributes, children)
 }
 
+/// `<!DOCTYPE html>` declaration
+///
+/// This allows rendering a full page from elements, without using a [`Document`](crate::Document).
+///
+/// # Example
+///
+/// ```
+/// use fun_html::{attr::lang, elt::{body, doctype, fragment, head, html_element}};
+///
+/// let page = fragment([doctype(), html_element([lang("en")], [head([], []), body([], [])])]);
+/// assert_eq!(page.to_string(), r#"<!DOCTYPE html><html lang="en"><head></head><body></body></html>"#);
+/// ```
+pub fn doctype() -> Element {
+    raw(crate::DOCTYPE)
+}
+
+/// `<html>` root element
+///
+/// This renders the element without doctype. See [`crate::html`] to create a [`Document`](crate::Document).
+///
+/// Named `html_element` so that it does not conflict with [`crate::html`] when both are glob-imported.
+///
+/// # Example
+///
+/// ```
+/// use fun_html::{attr::{dir, lang, Dir}, elt::{body, head, html_element}};
+///
+/// let root = html_element([lang("ar"), dir(Dir::Rtl)], [head([], []), body([], [])]);
+/// assert_eq!(root.to_string(), r#"<html lang="ar" dir="rtl"><head></head><body></body></html>"#);
+/// ```
+pub fn html_element(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("html", attributes, children)
+}
+
 /// `<head>`
 pub fn head(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -61,17 +234,20 @@ pub fn meta(attributes: impl IntoIterator<Item = Attribute>) -> Element {
 /// `<meta charset="UFT-8">`
 #[deprecated(since = "1.5.0", note = "renamed to 'meta_charset_utf8'")]
 pub fn meta_charset_utf_8() -> Element {
-    raw("<meta charset=\"UTF-8\">")
+    meta_charset_utf8()
 }
 
 /// `<meta charset="UFT-8">`
 pub fn meta_charset_utf8() -> Element {
-    raw("<meta charset=\"UTF-8\">")
+    meta([attr::charset_utf8()])
 }
 
 /// `<meta name="viewport" content="width=device-width, initial-scale=1.0">`
 pub fn meta_viewport() -> Element {
-    raw("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\">")
+    meta([
+        name("viewport"),
+        content("width=device-width, initial-scale=1.0"),
+    ])
 }
 
 /// `<meta name="color-scheme content="{scheme}">
@@ -79,6 +255,59 @@ pub fn meta_color_scheme(scheme: impl Into<Cow<'static, str>>) -> Element {
     meta([name("color-scheme"), content(scheme)])
 }
 
+/// `<meta name="description" content="{text}">`
+pub fn meta_description(text: impl Into<Cow<'static, str>>) -> Element {
+    meta([name("description"), content(text)])
+}
+
+/// `<meta property="og:title" content="{title}">` ([Open Graph](https://ogp.me))
+pub fn meta_og_title(title: impl Into<Cow<'static, str>>) -> Element {
+    meta([attr::property("og:title"), content(title)])
+}
+
+/// `<meta property="og:description" content="{description}">` ([Open Graph](https://ogp.me))
+pub fn meta_og_description(description: impl Into<Cow<'static, str>>) -> Element {
+    meta([attr::property("og:description"), content(description)])
+}
+
+/// `<meta property="og:image" content="{url}">` ([Open Graph](https://ogp.me))
+pub fn meta_og_image(url: impl Into<Cow<'static, str>>) -> Element {
+    meta([attr::property("og:image"), content(url)])
+}
+
+/// `<meta property="og:type" content="{type_}">` ([Open Graph](https://ogp.me))
+pub fn meta_og_type(type_: impl Into<Cow<'static, str>>) -> Element {
+    meta([attr::property("og:type"), content(type_)])
+}
+
+/// `<meta property="og:url" content="{url}">` ([Open Graph](https://ogp.me))
+pub fn meta_og_url(url: impl Into<Cow<'static, str>>) -> Element {
+    meta([attr::property("og:url"), content(url)])
+}
+
+/// `<meta name="twitter:card" content="{card}">` ([Twitter Card](https://developer.x.com/en/docs/x-for-websites/cards/overview/markup))
+///
+/// Twitter card tags use the `name` attribute (unlike Open Graph tags, which use `property`),
+/// as documented by X, so all the `meta_twitter_*` functions render `name="twitter:..."`.
+pub fn meta_twitter_card(card: impl Into<Cow<'static, str>>) -> Element {
+    meta([name("twitter:card"), content(card)])
+}
+
+/// `<meta name="twitter:title" content="{title}">` ([Twitter Card](https://developer.x.com/en/docs/x-for-websites/cards/overview/markup))
+pub fn meta_twitter_title(title: impl Into<Cow<'static, str>>) -> Element {
+    meta([name("twitter:title"), content(title)])
+}
+
+/// `<meta name="twitter:description" content="{description}">` ([Twitter Card](https://developer.x.com/en/docs/x-for-websites/cards/overview/markup))
+pub fn meta_twitter_description(description: impl Into<Cow<'static, str>>) -> Element {
+    meta([name("twitter:description"), content(description)])
+}
+
+/// `<meta name="twitter:image" content="{url}">` ([Twitter Card](https://developer.x.com/en/docs/x-for-websites/cards/overview/markup))
+pub fn meta_twitter_image(url: impl Into<Cow<'static, str>>) -> Element {
+    meta([name("twitter:image"), content(url)])
+}
+
 /// `<link>`
 pub fn link(attributes: impl IntoIterator<Item = Attribute>) -> Element {
     Element::new_void("link", attributes)
@@ -89,6 +318,68 @@ pub fn link_stylesheet(url: impl Into<Cow<'static, str>>) -> Element {
     link([rel("stylesheet"), href(url)])
 }
 
+/// `<link rel="canonical" href="{url}">`
+pub fn link_canonical(url: impl Into<Cow<'static, str>>) -> Element {
+    link([rel("canonical"), href(url)])
+}
+
+/// `<link rel="icon" href="{href}">`
+pub fn link_icon(href: impl Into<Cow<'static, str>>) -> Element {
+    link([rel("icon"), attr::href(href)])
+}
+
+/// `<link rel="preload" href="{href}" as="{as_}">`
+///
+/// Fonts must also be fetched in CORS mode to be reused:
+///
+/// ```
+/// use fun_html::{attr::{as_, crossorigin_anonymous, href, rel, type_}, elt::link};
+///
+/// let font = link([
+///   rel("preload"),
+///   href("/font.woff2"),
+///   as_("font"),
+///   type_("font/woff2"),
+///   crossorigin_anonymous(),
+/// ]);
+/// ```
+pub fn link_preload(
+    href: impl Into<Cow<'static, str>>,
+    as_: impl Into<Cow<'static, str>>,
+) -> Element {
+    link([rel("preload"), attr::href(href), attr::as_(as_)])
+}
+
+/// `<link rel="prefetch" href="{href}">`
+pub fn link_prefetch(href: impl Into<Cow<'static, str>>) -> Element {
+    link([rel("prefetch"), attr::href(href)])
+}
+
+/// `<link rel="icon" href="{href}" type="{type_}" sizes="{sizes}">`
+///
+/// # Example
+///
+/// ```
+/// use fun_html::elt::link_icon_with_type;
+///
+/// assert_eq!(
+///   link_icon_with_type("/icon-32.png", "image/png", "32x32").to_string(),
+///   r#"<link rel="icon" href="/icon-32.png" type="image/png" sizes="32x32">"#,
+/// );
+/// ```
+pub fn link_icon_with_type(
+    href: impl Into<Cow<'static, str>>,
+    type_: impl Into<Cow<'static, str>>,
+    sizes: impl Into<Cow<'static, str>>,
+) -> Element {
+    link([
+        rel("icon"),
+        attr::href(href),
+        attr::type_(type_),
+        attr::sizes(sizes),
+    ])
+}
+
 /// `<script>`
 pub fn script(attributes: impl IntoIterator<Item = Attribute>, content: &'static str) -> Element {
     Element::new(
@@ -103,6 +394,86 @@ pub fn script_empty(attributes: impl IntoIterator<Item = Attribute>) -> Element
     Element::new("script", attributes, [])
 }
 
+/// `<script>` with inline JavaScript
+///
+/// `</script` (case-insensitive) is written as `<\/script` and `<!--` as `<\!--`,
+/// so that the content cannot end the script element early.
+///
+/// Unlike [`script`], which only escapes `</script`, this is meant for content that is not fully controlled.
+/// Both escapes keep the same value in string and template literals,
+/// but `<\!--` is a syntax error in a regular expression with the `u` flag.
+///
+/// # Example
+///
+/// ```
+/// use fun_html::elt::script_inline;
+///
+/// assert_eq!(
+///   script_inline("document.write('</script>')").to_string(),
+///   r#"<script>document.write('<\/script>')</script>"#,
+/// );
+/// ```
+pub fn script_inline(js: impl Into<Cow<'static, str>>) -> Element {
+    script_inline_with([], js)
+}
+
+/// `<script>` with inline JavaScript and attributes (such as `type` or `nonce`)
+///
+/// The content is escaped like in [`script_inline`].
+pub fn script_inline_with(
+    attributes: impl IntoIterator<Item = Attribute>,
+    js: impl Into<Cow<'static, str>>,
+) -> Element {
+    let js = js.into();
+    let js = if js.contains("<!--") {
+        js.replace("<!--", "<\\!--").into()
+    } else {
+        js
+    };
+    Element::new("script", attributes, [Element(ElementInner::Script(js))])
+}
+
+/// `<script type="application/json" id="{id}">` containing the JSON serialization of `value`
+///
+/// `<`, `>` and `&` are written as `\u003c`, `\u003e` and `\u0026`,
+/// so that the payload can neither end the script element nor start a comment,
+/// while still being parsed back to the same value.
+///
+/// # Errors
+///
+/// Returns the error of `serde_json` if `value` cannot be serialized.
+///
+/// # Example
+///
+/// ```
+/// use fun_html::elt::script_json;
+///
+/// assert_eq!(
+///   script_json("state", &["</script>"]).unwrap().to_string(),
+///   r#"<script type="application/json" id="state">["\u003c/script\u003e"]</script>"#,
+/// );
+/// ```
+#[cfg(feature = "serde_json")]
+pub fn script_json(
+    id: impl Into<Cow<'static, str>>,
+    value: &impl serde::Serialize,
+) -> Result<Element, serde_json::Error> {
+    let json = serde_json::to_string(value)?;
+    let mut escaped = String::with_capacity(json.len());
+    for c in json.chars() {
+        match c {
+            '<' => escaped.push_str("\\u003c"),
+            '>' => escaped.push_str("\\u003e"),
+            '&' => escaped.push_str("\\u0026"),
+            c => escaped.push(c),
+        }
+    }
+    Ok(script_inline_with(
+        [attr::type_("application/json"), attr::id(id)],
+        escaped,
+    ))
+}
+
 /// `<title>`
 pub fn title(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -265,6 +636,41 @@ pub fn tfoot(
     Element::new("tfoot", attributes, children)
 }
 
+/// `<table>` with a header row (`<thead>`) and a row (in `<tbody>`) for each item of `rows`
+///
+/// Each header is wrapped in a `<th>` and each cell in a `<td>`.
+/// Rows are rendered as-is, even if they do not have the same number of cells as the header.
+/// The `<tbody>` is omitted when there is no row.
+///
+/// # Example
+///
+/// ```
+/// use fun_html::elt::{table_from_rows, text};
+///
+/// let table = table_from_rows([], [text("Name"), text("Age")], [[text("Jo"), text("42")]]);
+/// assert_eq!(
+///   table.to_string(),
+///   "<table><thead><tr><th>Name</th><th>Age</th></tr></thead><tbody><tr><td>Jo</td><td>42</td></tr></tbody></table>",
+/// );
+/// ```
+pub fn table_from_rows(
+    attributes: impl IntoIterator<Item = Attribute>,
+    headers: impl IntoIterator<Item = Element>,
+    rows: impl IntoIterator<Item = impl IntoIterator<Item = Element>>,
+) -> Element {
+    let header = thead([], [tr([], headers.into_iter().map(|h| th([], [h])))]);
+    let rows: Vec<Element> = rows
+        .into_iter()
+        .map(|row| tr([], row.into_iter().map(|cell| td([], [cell]))))
+        .collect();
+    let body = if rows.is_empty() {
+        none()
+    } else {
+        tbody([], rows)
+    };
+    table(attributes, [header, body])
+}
+
 /// `<section>`
 pub fn section(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -318,6 +724,11 @@ pub fn img(attributes: impl IntoIterator<Item = Attribute>) -> Element {
     Element::new_void("img", attributes)
 }
 
+/// `<source>`
+pub fn source(attributes: impl IntoIterator<Item = Attribute>) -> Element {
+    Element::new_void("source", attributes)
+}
+
 /// `<ul>`
 pub fn ul(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -342,6 +753,35 @@ pub fn li(
     Element::new("li", attributes, children)
 }
 
+/// `<ul>` with each item wrapped in a `<li>`
+///
+/// Every item is wrapped, even if it already is a `<li>`.
+///
+/// # Example
+///
+/// ```
+/// use fun_html::elt::{text, ul_from};
+///
+/// let list = ul_from([], ["a", "b"].into_iter().map(text));
+/// assert_eq!(list.to_string(), "<ul><li>a</li><li>b</li></ul>");
+/// ```
+pub fn ul_from(
+    attributes: impl IntoIterator<Item = Attribute>,
+    items: impl IntoIterator<Item = Element>,
+) -> Element {
+    ul(attributes, items.into_iter().map(|item| li([], [item])))
+}
+
+/// `<ol>` with each item wrapped in a `<li>`
+///
+/// See [`ul_from`]
+pub fn ol_from(
+    attributes: impl IntoIterator<Item = Attribute>,
+    items: impl IntoIterator<Item = Element>,
+) -> Element {
+    ol(attributes, items.into_iter().map(|item| li([], [item])))
+}
+
 /// `<form>`
 pub fn form(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -363,6 +803,103 @@ pub fn input(attributes: impl IntoIterator<Item = Attribute>) -> Element {
     Element::new_void("input", attributes)
 }
 
+/// `<input type="text" name="{name}" value="{value}">` followed by the `extra` attributes
+///
+/// # Example
+///
+/// ```
+/// use fun_html::{attr::placeholder, elt::input_text};
+///
+/// assert_eq!(
+///   input_text("q", "", [placeholder("Search")]).to_string(),
+///   r#"<input type="text" name="q" value="" placeholder="Search">"#,
+/// );
+/// ```
+pub fn input_text(
+    name: impl Into<Cow<'static, str>>,
+    value: impl Into<Cow<'static, str>>,
+    extra: impl IntoIterator<Item = Attribute>,
+) -> Element {
+    input(
+        [attr::type_text(), attr::name(name), attr::value(value)]
+            .into_iter()
+            .chain(extra),
+    )
+}
+
+/// `<input type="hidden" name="{name}" value="{value}">` followed by the `extra` attributes
+pub fn input_hidden(
+    name: impl Into<Cow<'static, str>>,
+    value: impl Into<Cow<'static, str>>,
+    extra: impl IntoIterator<Item = Attribute>,
+) -> Element {
+    input(
+        [attr::type_hidden(), attr::name(name), attr::value(value)]
+            .into_iter()
+            .chain(extra),
+    )
+}
+
+/// `<input type="password" name="{name}">` followed by the `extra` attributes
+pub fn input_password(
+    name: impl Into<Cow<'static, str>>,
+    extra: impl IntoIterator<Item = Attribute>,
+) -> Element {
+    input(
+        [attr::type_password(), attr::name(name)]
+            .into_iter()
+            .chain(extra),
+    )
+}
+
+/// `<input type="checkbox" name="{name}">` (with the `checked` flag if `checked` is `true`) followed by the `extra` attributes
+pub fn input_checkbox(
+    name: impl Into<Cow<'static, str>>,
+    checked: bool,
+    extra: impl IntoIterator<Item = Attribute>,
+) -> Element {
+    input(
+        [
+            attr::type_checkbox(),
+            attr::name(name),
+            Attribute::maybe(checked, attr::checked()).into(),
+        ]
+        .into_iter()
+        .chain(extra),
+    )
+}
+
+/// `<input type="radio" name="{name}" value="{value}">` (with the `checked` flag if `checked` is `true`) followed by the `extra` attributes
+pub fn input_radio(
+    name: impl Into<Cow<'static, str>>,
+    value: impl Into<Cow<'static, str>>,
+    checked: bool,
+    extra: impl IntoIterator<Item = Attribute>,
+) -> Element {
+    input(
+        [
+            attr::type_radio(),
+            attr::name(name),
+            attr::value(value),
+            Attribute::maybe(checked, attr::checked()).into(),
+        ]
+        .into_iter()
+        .chain(extra),
+    )
+}
+
+/// `<input type="submit" value="{label}">` followed by the `extra` attributes
+pub fn input_submit(
+    label: impl Into<Cow<'static, str>>,
+    extra: impl IntoIterator<Item = Attribute>,
+) -> Element {
+    input(
+        [attr::type_submit(), attr::value(label)]
+            .into_iter()
+            .chain(extra),
+    )
+}
+
 /// `<textarea>`
 pub fn textarea(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -387,6 +924,47 @@ pub fn option(
     Element::new("option", attributes, children)
 }
 
+/// `<select>` with an `<option>` for each `(value, label)` pair
+///
+/// The option whose value is equal to `selected` gets the `selected` attribute.
+/// No option is selected if there is no match.
+///
+/// # Example
+///
+/// ```
+/// use fun_html::{attr::name, elt::select_with_options};
+///
+/// let select = select_with_options([name("size")], [("s", "Small"), ("l", "Large")], Some("l"));
+/// assert_eq!(
+///   select.to_string(),
+///   r#"<select name="size"><option value="s">Small</option><option value="l" selected>Large</option></select>"#,
+/// );
+/// ```
+pub fn select_with_options<V, L>(
+    attributes: impl IntoIterator<Item = Attribute>,
+    options: impl IntoIterator<Item = (V, L)>,
+    selected: Option<&str>,
+) -> Element
+where
+    V: Into<Cow<'static, str>>,
+    L: Into<Cow<'static, str>>,
+{
+    select(
+        attributes,
+        options.into_iter().map(|(value, label)| {
+            let value = value.into();
+            let is_selected = selected == Some(&*value);
+            option(
+                [
+                    attr::value(value),
+                    Attribute::maybe(is_selected, attr::selected()).into(),
+                ],
+                [text(label)],
+            )
+        }),
+    )
+}
+
 /// `<button>`
 pub fn button(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -403,11 +981,66 @@ pub fn label(
     Element::new("label", attributes, children)
 }
 
+/// `<label for="{id}">` followed by `<input id="{id}">`
+///
+/// The two elements are returned as siblings, without any wrapping element.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::type_email, elt::labeled_input};
+/// assert_eq!(
+///     labeled_input("email", "Email", [type_email()]).to_string(),
+///     r#"<label for="email">Email</label><input id="email" type="email">"#,
+/// );
+/// ```
+pub fn labeled_input(
+    id: impl Into<Cow<'static, str>>,
+    label_text: impl Into<Cow<'static, str>>,
+    input_attributes: impl IntoIterator<Item = Attribute>,
+) -> Element {
+    let id = id.into();
+    [
+        label([for_(id.clone())], [text(label_text)]),
+        input(core::iter::once(attr::id(id)).chain(input_attributes)),
+    ]
+    .into()
+}
+
 /// HTML escaped text
+///
+/// Strings (`&'static str`, `String` and `Cow<'static, str>`) can also be converted into a text element with `into()`,
+/// which escapes them the same way. Only [`raw`] and [`raw_unsafe`] insert HTML without escaping.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::elt::{p, raw, text};
+/// assert_eq!(p([], [text("<b>Hi</b>")]).to_string(), "<p>&lt;b&gt;Hi&lt;/b&gt;</p>");
+/// assert_eq!(p([], ["<b>Hi</b>".into()]).to_string(), "<p>&lt;b&gt;Hi&lt;/b&gt;</p>");
+/// assert_eq!(p([], [raw("<b>Hi</b>")]).to_string(), "<p><b>Hi</b></p>");
+/// ```
 pub fn text(value: impl Into<Cow<'static, str>>) -> Element {
     ElementInner::Text(value.into()).into()
 }
 
+/// HTML escaped text of a value implementing `Display`
+///
+/// The value is formatted immediately (into an owned string) and then escaped when rendered.
+///
+/// Integers and floats can also be converted into a text element with `into()`.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::elt::{td, text_display};
+/// assert_eq!(td([], [text_display(42)]).to_string(), "<td>42</td>");
+/// assert_eq!(td([], [1.5.into()]).to_string(), "<td>1.5</td>");
+/// ```
+pub fn text_display(value: impl core::fmt::Display) -> Element {
+    text(format!("{value}"))
+}
+
 /// Inline raw HTML without escaping
 ///
 /// This function is considered safe because the HTML being inlined must be known at compile time
@@ -427,20 +1060,184 @@ pub fn raw_unsafe(html: String) -> Element {
     ElementInner::Raw(html.into()).into()
 }
 
+/// Element built by calling `build` when it is rendered, rather than when it is created
+///
+/// This is useful for parts that are expensive to build, and may not be rendered at all.
+/// The closure is called exactly once each time the element is rendered
+/// (rendering the same element twice calls it twice), and is shared by the clones of the element.
+/// It must be `Send` and `Sync` so that elements can still be sent across threads.
+///
+/// Note that lazy elements are considered inline when pretty-printing (see [`Element::to_string_pretty`]).
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::elt::{div, lazy, text};
+/// let element = div([], [lazy(|| text("expensive"))]);
+/// assert_eq!(element.to_string(), "<div>expensive</div>");
+/// ```
+pub fn lazy(build: impl Fn() -> Element + Send + Sync + 'static) -> Element {
+    ElementInner::Lazy(LazyElement(Arc::new(build))).into()
+}
+
+/// HTML comment (`<!--{text}-->`)
+///
+/// The text is not escaped.
+///
+/// ## Panics
+///
+/// In debug builds, panics if the text is not a valid comment (see [`comment_checked`]).
+/// In release builds, a space is inserted to break up the invalid sequences
+/// (between two consecutive `-`, before a leading `>` or `->` and after a trailing `-`),
+/// so that the text can never end the comment early.
+///
+/// Use [`comment_checked`] for text that is not known to be valid.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::elt::comment;
+/// assert_eq!(comment(" build 42 ").to_string(), "<!-- build 42 -->");
+/// ```
+pub fn comment(text: impl Into<Cow<'static, str>>) -> Element {
+    let mut text = text.into();
+    if let Err(err) = validate_comment(&text) {
+        debug_assert!(false, "{err}: '{text}'");
+        text = sanitize_comment(&text).into();
+    }
+    ElementInner::Comment(text).into()
+}
+
+/// HTML comment (`<!--{text}-->`), returning an error if the text is not a valid comment
+///
+/// The text of a comment must not contain `--`, start with `>` or `->`, nor end with `-`.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::elt::{comment_checked, InvalidComment};
+/// assert_eq!(comment_checked("ok").unwrap().to_string(), "<!--ok-->");
+/// assert_eq!(comment_checked("a -- b").unwrap_err(), InvalidComment::ContainsDoubleDash);
+/// ```
+pub fn comment_checked(text: impl Into<Cow<'static, str>>) -> Result<Element, InvalidComment> {
+    let text = text.into();
+    validate_comment(&text)?;
+    Ok(ElementInner::Comment(text).into())
+}
+
+fn validate_comment(text: &str) -> Result<(), InvalidComment> {
+    if text.starts_with('>') || text.starts_with("->") {
+        Err(InvalidComment::StartsWithGreaterThan)
+    } else if text.contains("--") {
+        Err(InvalidComment::ContainsDoubleDash)
+    } else if text.ends_with('-') {
+        Err(InvalidComment::EndsWithDash)
+    } else {
+        Ok(())
+    }
+}
+
+fn sanitize_comment(text: &str) -> String {
+    let mut sanitized = String::with_capacity(text.len() + 2);
+    if text.starts_with('>') || text.starts_with("->") {
+        sanitized.push(' ');
+    }
+    for c in text.chars() {
+        if c == '-' && sanitized.ends_with('-') {
+            sanitized.push(' ');
+        }
+        sanitized.push(c);
+    }
+    if sanitized.ends_with('-') {
+        sanitized.push(' ');
+    }
+    sanitized
+}
+
+/// Reason why a text cannot be rendered as an HTML comment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidComment {
+    /// The text starts with `>` or `->`
+    StartsWithGreaterThan,
+    /// The text contains `--`
+    ContainsDoubleDash,
+    /// The text ends with `-`
+    EndsWithDash,
+}
+
+impl core::fmt::Display for InvalidComment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::StartsWithGreaterThan => "comment must not start with '>' or '->'",
+            Self::ContainsDoubleDash => "comment must not contain '--'",
+            Self::EndsWithDash => "comment must not end with '-'",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidComment {}
+
+/// Escaped text, same as [`text`]
 impl From<Cow<'static, str>> for Element {
     fn from(value: Cow<'static, str>) -> Self {
         text(value)
     }
 }
 
+/// Escaped text, same as [`text`]
 impl From<&'static str> for Element {
     fn from(value: &'static str) -> Self {
         text(value)
     }
 }
 
+/// Escaped text, same as [`text`]
 impl From<String> for Element {
     fn from(value: String) -> Self {
         text(value)
     }
 }
+
+/// Escaped text, same as [`text_display`]
+impl From<i32> for Element {
+    fn from(value: i32) -> Self {
+        text_display(value)
+    }
+}
+
+/// Escaped text, same as [`text_display`]
+impl From<u32> for Element {
+    fn from(value: u32) -> Self {
+        text_display(value)
+    }
+}
+
+/// Escaped text, same as [`text_display`]
+impl From<i64> for Element {
+    fn from(value: i64) -> Self {
+        text_display(value)
+    }
+}
+
+/// Escaped text, same as [`text_display`]
+impl From<u64> for Element {
+    fn from(value: u64) -> Self {
+        text_display(value)
+    }
+}
+
+/// Escaped text, same as [`text_display`]
+impl From<usize> for Element {
+    fn from(value: usize) -> Self {
+        text_display(value)
+    }
+}
+
+/// Escaped text, same as [`text_display`]
+impl From<f64> for Element {
+    fn from(value: f64) -> Self {
+        text_display(value)
+    }
+}
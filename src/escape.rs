@@ -0,0 +1,108 @@
+//! HTML escaping
+//!
+//! These are the functions used when rendering [`Element`](crate::Element)s and [`Attribute`](crate::Attribute)s,
+//! exposed to produce escaped fragments outside of the element tree.
+//! Only ASCII characters are ever escaped, and multi-byte UTF-8 characters are left untouched.
+
+use alloc::{borrow::Cow, string::String};
+use core::fmt::{self, Write};
+
+/// Escape text content: `&`, `<` and `>` are replaced by `&amp;`, `&lt;` and `&gt;`
+///
+/// Returns [`Cow::Borrowed`] when there is nothing to escape.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::escape;
+/// assert_eq!(escape::text("a < b & c"), "a &lt; b &amp; c");
+/// assert_eq!(escape::text(r#""quotes" are kept"#), r#""quotes" are kept"#);
+/// ```
+pub fn text(text: &str) -> Cow<'_, str> {
+    escape(text, text_escape)
+}
+
+/// Escape a double-quoted attribute value: `&`, `<`, `>` and `"` are replaced by `&amp;`, `&lt;`, `&gt;` and `&quot;`
+///
+/// Returns [`Cow::Borrowed`] when there is nothing to escape.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::escape;
+/// assert_eq!(escape::attribute(r#"say "hi""#), "say &quot;hi&quot;");
+/// ```
+pub fn attribute(value: &str) -> Cow<'_, str> {
+    escape(value, attribute_escape)
+}
+
+/// Write text content, escaping `&`, `<` and `>`
+pub(crate) fn write_text(w: &mut impl Write, text: &str) -> fmt::Result {
+    write_escaped(w, text, text_escape)
+}
+
+/// Write a double-quoted attribute value, escaping `&`, `<`, `>` and `"`
+pub(crate) fn write_attribute_value(w: &mut impl Write, value: &str) -> fmt::Result {
+    write_escaped(w, value, attribute_escape)
+}
+
+fn text_escape(b: u8) -> Option<&'static str> {
+    match b {
+        b'&' => Some("&amp;"),
+        b'<' => Some("&lt;"),
+        b'>' => Some("&gt;"),
+        _ => None,
+    }
+}
+
+fn attribute_escape(b: u8) -> Option<&'static str> {
+    match b {
+        b'"' => Some("&quot;"),
+        _ => text_escape(b),
+    }
+}
+
+fn escape(value: &str, escape: fn(u8) -> Option<&'static str>) -> Cow<'_, str> {
+    if value.bytes().all(|b| escape(b).is_none()) {
+        return Cow::Borrowed(value);
+    }
+    let mut escaped = String::with_capacity(value.len() + value.len() / 8);
+    write_escaped(&mut escaped, value, escape).unwrap();
+    Cow::Owned(escaped)
+}
+
+/// Write the content of a `<script>` element, escaping `</script` (case-insensitive) as `<\/script`
+pub(crate) fn write_script(w: &mut impl Write, script: &str) -> fmt::Result {
+    const END_TAG: &[u8] = b"</script";
+    let bytes = script.as_bytes();
+    let mut last = 0;
+    let mut i = 0;
+    while i + END_TAG.len() <= bytes.len() {
+        if bytes[i..i + END_TAG.len()].eq_ignore_ascii_case(END_TAG) {
+            w.write_str(&script[last..=i])?;
+            w.write_char('\\')?;
+            last = i + 1;
+            i += END_TAG.len();
+        } else {
+            i += 1;
+        }
+    }
+    w.write_str(&script[last..])
+}
+
+/// Only ASCII bytes may be escaped, so that slicing between them is always on a char boundary
+fn write_escaped(
+    w: &mut impl Write,
+    value: &str,
+    escape: impl Fn(u8) -> Option<&'static str>,
+) -> fmt::Result {
+    let mut last = 0;
+    for (i, b) in value.bytes().enumerate() {
+        if let Some(escaped) = escape(b) {
+            w.write_str(&value[last..i])?;
+            w.write_str(escaped)?;
+            last = i + 1;
+        }
+    }
+    w.write_str(&value[last..])
+}
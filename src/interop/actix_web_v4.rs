@@ -0,0 +1,23 @@
+use actix_web_v4::{body::BoxBody, HttpRequest, HttpResponse, Responder};
+
+impl Responder for crate::Element {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        html_response(self.to_string())
+    }
+}
+
+impl Responder for crate::Document {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        html_response(self.to_string())
+    }
+}
+
+fn html_response(body: String) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(body)
+}
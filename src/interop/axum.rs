@@ -1,12 +1,51 @@
+use core::{
+    convert::Infallible,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
 use axum_core::{
     body::Body,
     response::{IntoResponse, Response},
 };
+use futures_core::Stream;
+
+use crate::{Document, Element};
+
+/// Target length of the chunks sent by [`Document::into_stream_response`]
+const STREAM_CHUNK_LEN: usize = 8 * 1024;
 
-use crate::Document;
+const CONTENT_TYPE: (&str, &str) = ("content-type", "text/html; charset=utf-8");
 
 impl IntoResponse for Document {
     fn into_response(self) -> Response {
-        Response::new(Body::new(self.to_string()))
+        ([CONTENT_TYPE], self.to_string()).into_response()
+    }
+}
+
+impl IntoResponse for Element {
+    fn into_response(self) -> Response {
+        ([CONTENT_TYPE], self.to_string()).into_response()
+    }
+}
+
+impl Document {
+    /// Create an axum response whose body is streamed as the document is rendered
+    ///
+    /// The document is rendered in chunks (see [`Document::render_chunks`]), so that the beginning of the page
+    /// is sent before the rest is rendered. The `content-type` header is set to `text/html; charset=utf-8`.
+    pub fn into_stream_response(self) -> Response {
+        let body = Body::from_stream(ChunkStream(self.render_chunks(STREAM_CHUNK_LEN)));
+        ([CONTENT_TYPE], body).into_response()
+    }
+}
+
+struct ChunkStream<I>(I);
+
+impl<I: Iterator<Item = String> + Unpin> Stream for ChunkStream<I> {
+    type Item = Result<String, Infallible>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.0.next().map(Ok))
     }
 }
@@ -0,0 +1,25 @@
+use hyper_v014::{
+    header::{HeaderValue, CONTENT_TYPE},
+    Body, Response,
+};
+
+impl From<crate::Element> for Response<Body> {
+    fn from(value: crate::Element) -> Self {
+        html_response(value.to_string())
+    }
+}
+
+impl From<crate::Document> for Response<Body> {
+    fn from(value: crate::Document) -> Self {
+        html_response(value.to_string())
+    }
+}
+
+fn html_response(body: String) -> Response<Body> {
+    let mut response = Response::new(Body::from(body));
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("text/html; charset=utf-8"),
+    );
+    response
+}
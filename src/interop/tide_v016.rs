@@ -0,0 +1,20 @@
+use tide_v016::{http::mime, Response, StatusCode};
+
+impl From<crate::Element> for Response {
+    fn from(value: crate::Element) -> Self {
+        html_response(value.to_string())
+    }
+}
+
+impl From<crate::Document> for Response {
+    fn from(value: crate::Document) -> Self {
+        html_response(value.to_string())
+    }
+}
+
+fn html_response(body: String) -> Response {
+    Response::builder(StatusCode::Ok)
+        .content_type(mime::HTML)
+        .body(body)
+        .build()
+}
@@ -0,0 +1,13 @@
+use warp_v03::reply::{html, Reply, Response};
+
+impl Reply for crate::Element {
+    fn into_response(self) -> Response {
+        html(self.to_string()).into_response()
+    }
+}
+
+impl Reply for crate::Document {
+    fn into_response(self) -> Response {
+        html(self.to_string()).into_response()
+    }
+}
@@ -60,15 +60,48 @@
 //! * `rocket_v05`: implements the [`Responder` trait from rocket 0.5](https://docs.rs/rocket/0.5/rocket/response/trait.Responder.html) for [`Document`] and [`Element`]
 //! * `salvo_v074`: implements the [`Scribe` trait from salvo 0.74](https://docs.rs/salvo/0.74/salvo/trait.Scribe.html) for [`Document`] and [`Element`]
 //! * `salvo_v076`: implements the [`Scribe` trait from salvo 0.76](https://docs.rs/salvo/0.76/salvo/trait.Scribe.html) for [`Document`] and [`Element`]
+//! * `axum`: implements the [`IntoResponse` trait from axum](https://docs.rs/axum/latest/axum/response/trait.IntoResponse.html) for [`Document`] and [`Element`]
+//! * `actix_web_v4`: implements the [`Responder` trait from actix-web 4](https://docs.rs/actix-web/4/actix_web/trait.Responder.html) for [`Document`] and [`Element`]
+//! * `hyper_v014`: implements conversion from [`Document`] and [`Element`] into a [`Response` from hyper 0.14](https://docs.rs/hyper/0.14/hyper/struct.Response.html)
+//! * `warp_v03`: implements the [`Reply` trait from warp 0.3](https://docs.rs/warp/0.3/warp/reply/trait.Reply.html) for [`Document`] and [`Element`]
+//! * `tide_v016`: implements conversion from [`Document`] and [`Element`] into a [`Response` from tide 0.16](https://docs.rs/tide/0.16/tide/struct.Response.html)
 //! * `maud_v026`: implements `Render` add provide conversion from/to `Markup` for [maud 0.26](https://docs.rs/maud/0.26)
+//! * `macros`: provides the [`html!`] macro with a declarative syntax to build elements, the [`attrs!`] macro for conditional attributes, and the [`text!`] macro for formatted text
+//! * `serde_json`: provides [`elt::script_json`] to embed a serialized value in a `<script type="application/json">` element
+//! * `alpine`: provides the [`attr::alpine`] module with attributes for [Alpine.js](https://alpinejs.dev)
 
 pub mod attr;
 pub mod conv;
 pub mod elt;
+pub mod escape;
+pub mod mathml;
+
+#[cfg(feature = "macros")]
+mod macros;
+mod render;
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+
+    pub fn text_from_args(args: core::fmt::Arguments<'_>) -> crate::Element {
+        match args.as_str() {
+            Some(text) => crate::elt::text(text),
+            None => crate::elt::text(alloc::fmt::format(args)),
+        }
+    }
+}
+
+pub use render::RenderOptions;
 
 mod interop {
+    #[cfg(feature = "actix_web_v4")]
+    mod actix_web_v4;
     #[cfg(feature = "axum")]
     mod axum;
+    #[cfg(feature = "hyper_v014")]
+    mod hyper_v014;
     #[cfg(feature = "maud_v026")]
     mod maud_v026;
     #[cfg(feature = "rocket_v05")]
@@ -77,11 +110,15 @@ mod interop {
     mod salvo_v074;
     #[cfg(feature = "salvo_v076")]
     mod salvo_v076;
+    #[cfg(feature = "tide_v016")]
+    mod tide_v016;
+    #[cfg(feature = "warp_v03")]
+    mod warp_v03;
 }
 
 extern crate alloc;
 
-use alloc::{borrow::Cow, fmt::Display, vec::Vec};
+use alloc::{borrow::Cow, fmt::Display, string::String, sync::Arc, vec::Vec};
 
 /// An HTML document (`<!DOCTYPE html>`)
 ///
@@ -93,22 +130,44 @@ use alloc::{borrow::Cow, fmt::Display, vec::Vec};
 ///
 /// assert_eq!(doc.to_string(), "<!DOCTYPE html>\n<html><head></head><body></body></html>");
 /// ```
-#[derive(Debug, Clone)]
-pub struct Document(Element);
+#[derive(Clone)]
+pub struct Document {
+    html: Element,
+    doctype: Option<Cow<'static, str>>,
+    xml_declaration: bool,
+}
 
 /// An HTML element
 ///
 /// It can be created via [`Self::new`], [`Self::new_void`]
 ///
+/// The `Display` implementation renders the HTML fragment (without doctype),
+/// so that an element can be embedded in format strings or written with `write!`.
+///
+/// ## Repeated attributes
+///
+/// When the same attribute is given more than once, it is rendered only once, at the position of its first occurrence:
+/// the values of `class` are joined by a space, the values of `style` are joined by `; `,
+/// and for any other attribute (including flags) the last occurrence wins.
+///
+/// ```
+/// # use fun_html::{attr::{class, id}, elt::div};
+/// assert_eq!(
+///     div([class(["card"]), id("a"), class(["active"]), id("b")], []).to_string(),
+///     r#"<div class="card active" id="b"></div>"#,
+/// );
+/// ```
+///
 /// ## Example
 ///
 /// ```
-/// # use fun_html::{Element, elt::div};
+/// # use fun_html::{Element, elt::{div, span}};
 /// let element: Element = div([], []);
 ///
 /// assert_eq!(element.to_string(), "<div></div>");
+/// assert_eq!(format!("Hello {}!", span([], ["world".into()])), "Hello <span>world</span>!");
 /// ```
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct Element(ElementInner);
 
 #[derive(Debug, Clone)]
@@ -125,10 +184,28 @@ enum ElementInner {
     Text(Cow<'static, str>),
     Script(Cow<'static, str>),
     Raw(Cow<'static, str>),
+    Comment(Cow<'static, str>),
+    Lazy(LazyElement),
     Multiple(Vec<Element>),
     None,
 }
 
+/// Closure building an element when it is rendered (see [`elt::lazy`])
+#[derive(Clone)]
+struct LazyElement(Arc<dyn Fn() -> Element + Send + Sync>);
+
+impl LazyElement {
+    fn build(&self) -> Element {
+        (self.0)()
+    }
+}
+
+impl core::fmt::Debug for LazyElement {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("LazyElement")
+    }
+}
+
 impl Default for ElementInner {
     fn default() -> Self {
         Self::None
@@ -138,20 +215,25 @@ impl Default for ElementInner {
 /// An attribute
 ///
 /// It can be created via [`Self::new`], [`Self::new_flag`]
-/// or by converting from ither `(&'static str, &'static str)` or `(&'static str, String)`.
+/// or by converting from either `(&'static str, &'static str)` or `(&'static str, String)`.
 ///
 /// See [`attr`] for a collection of common attributes
 ///
+/// The `Display` implementation renders the attribute exactly as it appears in an element
+/// (without the leading space separating it from the tag name or the previous attribute).
+///
 /// ## Example
 ///
 /// ```
-/// # use fun_html::attr::id;
+/// # use fun_html::attr::{id, required, placeholder};
 /// assert_eq!(
 ///   id("foo").to_string(),
 ///   r#"id="foo""#,
-/// )
+/// );
+/// assert_eq!(required().to_string(), "required");
+/// assert_eq!(placeholder(r#"say "hi""#).to_string(), r#"placeholder="say &quot;hi&quot;""#);
 /// ```
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct Attribute(AttributeInner);
 
 impl IntoIterator for Attribute {
@@ -165,8 +247,7 @@ impl IntoIterator for Attribute {
 
 #[derive(Debug, Clone)]
 enum AttributeInner {
-    KeyValue(Cow<'static, str>, Cow<'static, str>),
-    KeyValueInt(Cow<'static, str>, i32),
+    KeyValue(Cow<'static, str>, AttributeValue),
     Flag(Cow<'static, str>),
     None,
 }
@@ -177,9 +258,109 @@ impl Default for AttributeInner {
     }
 }
 
+/// The value of an [`Attribute`]
+///
+/// It can be created by converting from a string (`&'static str`, `String` or `Cow<'static, str>`),
+/// or from a `u32`, `i32`, `u64`, `f64`, `bool` or `char`.
+///
+/// Strings and chars are escaped when rendered, numbers are rendered in decimal notation,
+/// and booleans are rendered as `true` or `false`.
+///
+/// ## Example
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use fun_html::Attribute;
+/// assert_eq!(Attribute::new_value("tabindex", 3).to_string(), r#"tabindex="3""#);
+/// assert_eq!(Attribute::new_value("step", 0.5).to_string(), r#"step="0.5""#);
+/// assert_eq!(Attribute::new_value("draggable", true).to_string(), r#"draggable="true""#);
+/// assert_eq!(Attribute::new_value("accesskey", 's').to_string(), r#"accesskey="s""#);
+///
+/// // Strings are accepted as well
+/// assert_eq!(Attribute::new("id", "foo").to_string(), r#"id="foo""#);
+/// assert_eq!(Attribute::new_value("id", "foo".to_string()).to_string(), r#"id="foo""#);
+/// assert_eq!(Attribute::new_value("id", Cow::Borrowed("foo")).to_string(), r#"id="foo""#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AttributeValue(AttributeValueInner);
+
+#[derive(Debug, Clone)]
+enum AttributeValueInner {
+    Text(Cow<'static, str>),
+    Raw(Cow<'static, str>),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+}
+
+impl From<Cow<'static, str>> for AttributeValue {
+    fn from(value: Cow<'static, str>) -> Self {
+        Self(AttributeValueInner::Text(value))
+    }
+}
+
+impl From<&'static str> for AttributeValue {
+    fn from(value: &'static str) -> Self {
+        Self(AttributeValueInner::Text(value.into()))
+    }
+}
+
+impl From<String> for AttributeValue {
+    fn from(value: String) -> Self {
+        Self(AttributeValueInner::Text(value.into()))
+    }
+}
+
+impl From<i32> for AttributeValue {
+    fn from(value: i32) -> Self {
+        Self(AttributeValueInner::Int(value.into()))
+    }
+}
+
+impl From<u32> for AttributeValue {
+    fn from(value: u32) -> Self {
+        Self(AttributeValueInner::UInt(value.into()))
+    }
+}
+
+impl From<usize> for AttributeValue {
+    fn from(value: usize) -> Self {
+        Self(AttributeValueInner::UInt(value as u64))
+    }
+}
+
+impl From<u64> for AttributeValue {
+    fn from(value: u64) -> Self {
+        Self(AttributeValueInner::UInt(value))
+    }
+}
+
+impl From<f64> for AttributeValue {
+    fn from(value: f64) -> Self {
+        Self(AttributeValueInner::Float(value))
+    }
+}
+
+impl From<bool> for AttributeValue {
+    fn from(value: bool) -> Self {
+        Self(AttributeValueInner::Bool(value))
+    }
+}
+
+impl From<char> for AttributeValue {
+    fn from(value: char) -> Self {
+        Self(AttributeValueInner::Char(value))
+    }
+}
+
+const DOCTYPE: &str = "<!DOCTYPE html>";
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>";
+
 impl Default for Document {
     fn default() -> Self {
-        Self(Element::new(
+        Self::from_html(Element::new(
             "html",
             [],
             [Element::new("head", [], []), Element::new("body", [], [])],
@@ -187,9 +368,476 @@ impl Default for Document {
     }
 }
 
+impl Document {
+    /// Create a document from its `<head>` and `<body>` elements
+    ///
+    /// This is equivalent to `html([], [head, body])`, see [`html`] to set attributes on the `<html>` element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{Document, elt::{head, body, title, h1}};
+    /// let doc = Document::new(
+    ///     head([], [title([], "Greetings")]),
+    ///     body([], [h1([], ["Hello".into()])]),
+    /// );
+    /// assert_eq!(
+    ///     doc.to_string(),
+    ///     "<!DOCTYPE html>\n<html><head><title>Greetings</title></head><body><h1>Hello</h1></body></html>",
+    /// );
+    /// ```
+    pub fn new(head: Element, body: Element) -> Self {
+        html([], [head, body])
+    }
+
+    /// Add attributes to the `<html>` element (such as `lang` or `dir`)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{Document, attr::lang, elt::{head, body}};
+    /// let doc = Document::new(head([], []), body([], [])).with_html_attrs([lang("en")]);
+    /// assert_eq!(doc.to_string(), "<!DOCTYPE html>\n<html lang=\"en\"><head></head><body></body></html>");
+    /// ```
+    #[must_use]
+    pub fn with_html_attrs(mut self, attrs: impl IntoIterator<Item = Attribute>) -> Self {
+        if let ElementInner::Parent { attributes, .. } = &mut self.html.0 {
+            attributes.extend(attrs);
+        }
+        self
+    }
+
+    /// Add an attribute to the `<html>` element
+    ///
+    /// See [`Document::with_html_attrs`]
+    #[must_use]
+    pub fn with_html_attribute(self, attr: Attribute) -> Self {
+        self.with_html_attrs([attr])
+    }
+
+    /// Set the language of the document (`lang` attribute of the `<html>` element)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{Document, attr::Dir, elt::{head, body}};
+    /// let doc = Document::new(head([], []), body([], [])).with_lang("ar").with_dir(Dir::Rtl);
+    /// assert_eq!(doc.to_string(), "<!DOCTYPE html>\n<html lang=\"ar\" dir=\"rtl\"><head></head><body></body></html>");
+    /// ```
+    #[must_use]
+    pub fn with_lang(self, code: impl Into<Cow<'static, str>>) -> Self {
+        self.with_html_attribute(attr::lang(code))
+    }
+
+    /// Set the text direction of the document (`dir` attribute of the `<html>` element)
+    ///
+    /// See [`Document::with_lang`]
+    #[must_use]
+    pub fn with_dir(self, dir: attr::Dir) -> Self {
+        self.with_html_attribute(attr::dir(dir))
+    }
+
+    fn from_html(html: Element) -> Self {
+        Self {
+            html,
+            doctype: Some(DOCTYPE.into()),
+            xml_declaration: false,
+        }
+    }
+
+    /// Do not render any doctype
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{html, elt::{head, body}};
+    /// let doc = html([], [head([], []), body([], [])]).without_doctype();
+    /// assert_eq!(doc.to_string(), "<html><head></head><body></body></html>");
+    /// ```
+    #[must_use]
+    pub fn without_doctype(mut self) -> Self {
+        self.doctype = None;
+        self
+    }
+
+    /// Replace the `<!DOCTYPE html>` declaration by the given one
+    ///
+    /// The doctype is rendered as-is (without escaping) and followed by a new line.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{html, elt::{head, body}};
+    /// let doc = html([], [head([], []), body([], [])])
+    ///     .with_doctype(r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd">"#);
+    /// assert!(doc.to_string().starts_with("<!DOCTYPE html PUBLIC"));
+    /// ```
+    #[must_use]
+    pub fn with_doctype(mut self, doctype: impl Into<Cow<'static, str>>) -> Self {
+        self.doctype = Some(doctype.into());
+        self
+    }
+
+    /// Render the `<?xml version="1.0" encoding="UTF-8"?>` declaration before the doctype
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{html, elt::{head, body}};
+    /// let doc = html([], [head([], []), body([], [])]).with_xml_declaration();
+    /// assert_eq!(
+    ///     doc.to_string(),
+    ///     "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE html>\n<html><head></head><body></body></html>",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_xml_declaration(mut self) -> Self {
+        self.xml_declaration = true;
+        self
+    }
+
+    /// The `<head>` element of the document, if any
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{Document, elt::{head, body, title}};
+    /// let doc = Document::new(head([], [title([], "Hello")]), body([], []));
+    /// assert_eq!(doc.head().unwrap().to_string(), "<head><title>Hello</title></head>");
+    /// ```
+    pub fn head(&self) -> Option<&Element> {
+        self.child("head")
+    }
+
+    /// The `<body>` element of the document, if any
+    pub fn body(&self) -> Option<&Element> {
+        self.child("body")
+    }
+
+    fn child(&self, tag: &str) -> Option<&Element> {
+        match &self.html.0 {
+            ElementInner::Parent { children, .. } => {
+                children.iter().find(|child| child.is_parent_with_tag(tag))
+            }
+            _ => None,
+        }
+    }
+
+    /// Append an element to the `<head>`
+    ///
+    /// A `<head>` element is created if the document has none.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{Document, elt::{head, body, title, link_stylesheet}};
+    /// let mut doc = Document::new(head([], [title([], "Hello")]), body([], []));
+    /// doc.add_to_head(link_stylesheet("/extra.css"));
+    /// assert_eq!(
+    ///     doc.render_head(),
+    ///     r#"<head><title>Hello</title><link rel="stylesheet" href="/extra.css"></head>"#,
+    /// );
+    /// ```
+    pub fn add_to_head(&mut self, element: Element) {
+        if let Some(children) = self.children_mut("head") {
+            children.push(element);
+        }
+    }
+
+    /// Set the `<title>` of the document
+    ///
+    /// The first `<title>` found among the children of the `<head>` is replaced.
+    /// If there is none, the title is appended to the `<head>` (see [`Self::add_to_head`]).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{Document, elt::{head, body, title}};
+    /// let mut doc = Document::new(head([], [title([], "Hello")]), body([], []));
+    /// doc.set_title("Override");
+    /// assert_eq!(doc.render_head(), "<head><title>Override</title></head>");
+    /// ```
+    pub fn set_title(&mut self, title: impl Into<Cow<'static, str>>) {
+        let title = elt::title([], title);
+        if let Some(children) = self.children_mut("head") {
+            match children
+                .iter_mut()
+                .find(|child| child.is_parent_with_tag("title"))
+            {
+                Some(existing) => *existing = title,
+                None => children.push(title),
+            }
+        }
+    }
+
+    /// Append an element at the end of the `<body>`
+    ///
+    /// A `<body>` element is created if the document has none.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{Document, attr::{defer, src}, elt::{head, body, p, script}};
+    /// let mut doc = Document::new(head([], []), body([], [p([], ["Hello".into()])]));
+    /// doc.add_to_body_end(script([src("/analytics.js"), defer()], ""));
+    /// assert_eq!(
+    ///     doc.render_body(),
+    ///     r#"<body><p>Hello</p><script src="/analytics.js" defer></script></body>"#,
+    /// );
+    /// ```
+    pub fn add_to_body_end(&mut self, element: Element) {
+        if let Some(children) = self.children_mut("body") {
+            children.push(element);
+        }
+    }
+
+    /// Children of the `<head>` or `<body>` element, which is created if it does not exist
+    fn children_mut(&mut self, tag: &'static str) -> Option<&mut Vec<Element>> {
+        let html_children = match &mut self.html.0 {
+            ElementInner::Parent { children, .. } => children,
+            _ => return None,
+        };
+        let index = match html_children
+            .iter()
+            .position(|child| child.is_parent_with_tag(tag))
+        {
+            Some(index) => index,
+            None => {
+                let index = if tag == "head" {
+                    0
+                } else {
+                    html_children.len()
+                };
+                html_children.insert(index, Element::new(tag, [], []));
+                index
+            }
+        };
+        match &mut html_children[index].0 {
+            ElementInner::Parent { children, .. } => Some(children),
+            _ => None,
+        }
+    }
+
+    /// Render the `<head>` element (or an empty string if there is none)
+    ///
+    /// The result can be cached and reused with [`Self::render_with_cached_head`].
+    pub fn render_head(&self) -> String {
+        self.head()
+            .map(alloc::string::ToString::to_string)
+            .unwrap_or_default()
+    }
+
+    /// Render the `<body>` element (or an empty string if there is none)
+    pub fn render_body(&self) -> String {
+        self.body()
+            .map(alloc::string::ToString::to_string)
+            .unwrap_or_default()
+    }
+
+    /// Render the document, using the given HTML in place of the `<head>` element
+    ///
+    /// `head_html` is inserted without escaping and is expected to be the result of [`Self::render_head`]
+    /// for a document with the same head, in which case the output is identical to `to_string`.
+    /// This allows to render the head once and only render the body for each request.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{Document, elt::{head, body, title, p}};
+    /// let layout_head = head([], [title([], "My site")]);
+    /// let cached_head = Document::new(layout_head.clone(), body([], [])).render_head();
+    ///
+    /// let page = Document::new(layout_head, body([], [p([], ["Hello".into()])]));
+    /// assert_eq!(page.render_with_cached_head(&cached_head), page.to_string());
+    /// ```
+    pub fn render_with_cached_head(&self, head_html: &str) -> String {
+        let mut string =
+            String::with_capacity(self.prologue_len() + render::estimate_capacity(&self.html));
+        self.write_prologue(&mut string, true).unwrap();
+        let head = self.head();
+        match &self.html.0 {
+            ElementInner::Parent {
+                tag,
+                attributes,
+                children,
+            } => {
+                render::write_open_tag(&mut string, tag, attributes, &RenderOptions::new())
+                    .unwrap();
+                for child in children {
+                    if head.map_or(false, |head| core::ptr::eq(head, child)) {
+                        string.push_str(head_html);
+                    } else {
+                        child.write_to(&mut string).unwrap();
+                    }
+                }
+                render::write_close_tag(&mut string, tag).unwrap();
+            }
+            _ => self.html.write_to(&mut string).unwrap(),
+        }
+        string
+    }
+
+    /// Write the XML declaration and the doctype (unless `doctype` is `false`)
+    fn write_prologue(&self, w: &mut impl core::fmt::Write, doctype: bool) -> core::fmt::Result {
+        if self.xml_declaration {
+            w.write_str(XML_DECLARATION)?;
+            w.write_char('\n')?;
+        }
+        if let Some(declaration) = self.doctype.as_deref().filter(|_| doctype) {
+            w.write_str(declaration)?;
+            w.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    fn prologue_len(&self) -> usize {
+        let xml_declaration_len = if self.xml_declaration {
+            XML_DECLARATION.len() + 1
+        } else {
+            0
+        };
+        xml_declaration_len + self.doctype.as_ref().map_or(0, |d| d.len() + 1)
+    }
+
+    /// Render the document into the given writer
+    ///
+    /// This writes the document piece by piece without building an intermediate `String`.
+    pub fn write_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        self.write_prologue(w, true)?;
+        self.html.write_to(w)
+    }
+
+    /// Render the document with newlines and indentation, to make it easier to read
+    ///
+    /// Block-level children are rendered on their own line, indented by two spaces.
+    /// The content of whitespace-sensitive elements (`pre`, `textarea`, `script` and `style`) is left untouched.
+    ///
+    /// The `Display` implementation renders the compact form, which should be preferred in production.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{html, elt::{head, body, title, h1}};
+    /// let doc = html([], [head([], [title([], "Greetings")]), body([], [h1([], ["Hello".into()])])]);
+    ///
+    /// assert_eq!(doc.to_string_pretty(), "\
+    /// <!DOCTYPE html>
+    /// <html>
+    ///   <head>
+    ///     <title>Greetings</title>
+    ///   </head>
+    ///   <body>
+    ///     <h1>Hello</h1>
+    ///   </body>
+    /// </html>");
+    /// ```
+    #[doc(alias = "to_pretty_string")]
+    pub fn to_string_pretty(&self) -> String {
+        self.render_with(&RenderOptions::new().pretty(true))
+    }
+
+    /// Render XML-compatible markup
+    ///
+    /// This is a shortcut for `render_with(&RenderOptions::new().xhtml(true))`, see [`RenderOptions::xhtml`].
+    pub fn to_xhtml_string(&self) -> String {
+        self.render_with(&RenderOptions::new().xhtml(true))
+    }
+
+    /// Render the document with the given [`RenderOptions`]
+    ///
+    /// The doctype is rendered unless disabled with [`RenderOptions::doctype`].
+    pub fn render_with(&self, options: &RenderOptions) -> String {
+        let mut string = String::new();
+        self.write_prologue(&mut string, options.doctype_or(true))
+            .unwrap();
+        render::write_element_with(&mut string, &self.html, options).unwrap();
+        string
+    }
+
+    /// Render the document lazily, as chunks of about `chunk_len` bytes
+    ///
+    /// This allows to send very large documents incrementally, as the output is produced.
+    /// Chunks never split a character, an escape sequence or a tag, and only exceed `chunk_len`
+    /// when a single piece (such as a large text node) is longer than `chunk_len`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{html, elt::{head, body, p}};
+    /// let document = html([], [head([], []), body([], [p([], ["Hello".into()])])]);
+    /// let chunks: Vec<String> = document.clone().render_chunks(16).collect();
+    /// assert!(chunks.len() > 1);
+    /// assert_eq!(chunks.concat(), document.to_string());
+    /// ```
+    pub fn render_chunks(self, chunk_len: usize) -> impl Iterator<Item = String> {
+        let mut prologue = String::new();
+        self.write_prologue(&mut prologue, true).unwrap();
+        render::Chunks::new(prologue, self.html, chunk_len)
+    }
+
+    /// Render the document as UTF-8 bytes
+    ///
+    /// This is equivalent to `document.to_string().into_bytes()`,
+    /// but renders directly into a buffer whose capacity is reserved up front.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{html, elt::{head, body, h1}};
+    /// let document = html([], [head([], []), body([], [h1([], ["Hello".into()])])]);
+    /// assert_eq!(document.clone().into_bytes(), document.to_string().into_bytes());
+    /// ```
+    pub fn into_bytes(self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_bytes(&mut buf);
+        buf
+    }
+
+    /// Append the rendered document as UTF-8 bytes to the given buffer
+    pub fn write_bytes(&self, buf: &mut Vec<u8>) {
+        buf.reserve(self.prologue_len() + render::estimate_capacity(&self.html));
+        self.write_to(&mut render::BytesWriter(buf)).unwrap();
+    }
+
+    /// Append the rendered document to the given `String`
+    ///
+    /// The capacity of the `String` is reserved up front, based on an estimate of the rendered length.
+    /// This allows to reuse the same buffer for many documents.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{html, elt::{head, body}};
+    /// let mut buffer = String::new();
+    /// html([], [head([], []), body([], [])]).render_into(&mut buffer);
+    /// assert_eq!(buffer, "<!DOCTYPE html>\n<html><head></head><body></body></html>");
+    /// ```
+    pub fn render_into(&self, out: &mut String) {
+        out.reserve(self.prologue_len() + render::estimate_capacity(&self.html));
+        self.write_to(out).unwrap();
+    }
+
+    /// Render the document into the given [`std::io::Write`]
+    ///
+    /// This writes the document piece by piece without building an intermediate `String`.
+    #[cfg(feature = "std")]
+    pub fn write_to_io(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        render::io_adapter(w, |w| self.write_to(w))
+    }
+}
+
 impl Display for Document {
     fn fmt(&self, f: &mut alloc::fmt::Formatter<'_>) -> alloc::fmt::Result {
-        write!(f, "<!DOCTYPE html>\n{}", self.0)
+        self.write_to(f)
+    }
+}
+
+/// Renders the document, same as `to_string`
+impl From<Document> for String {
+    fn from(value: Document) -> Self {
+        let mut string = String::new();
+        value.render_into(&mut string);
+        string
     }
 }
 
@@ -203,7 +851,7 @@ impl Element {
         assert_valid_tag_name(tag);
         Self(ElementInner::Parent {
             tag,
-            attributes: attributes.into_iter().map(Into::into).collect(),
+            attributes: attributes.into_iter().collect(),
             children: children.into_iter().collect(),
         })
     }
@@ -220,6 +868,339 @@ impl Element {
             attributes: attributes.into_iter().collect(),
         })
     }
+
+    /// Add an attribute to the element
+    ///
+    /// The attribute is appended after the existing ones.
+    /// This makes it possible to add attributes to elements created by helpers that do not take any.
+    ///
+    /// This is a no-op for elements without a tag (text, raw HTML, comments, fragments, etc.)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{attr::{class, id}, elt::div};
+    /// let element = div([], []).with_attribute(id("main")).with_attribute(class(["big"]));
+    /// assert_eq!(element.to_string(), r#"<div id="main" class="big"></div>"#);
+    /// ```
+    #[must_use]
+    pub fn with_attribute(self, attr: Attribute) -> Self {
+        self.with_attributes([attr])
+    }
+
+    /// Add attributes to the element
+    ///
+    /// See [`Element::with_attribute`]
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{attr::{class, disabled, type_}, elt::button};
+    /// # let is_busy = true;
+    /// let mut submit = button([type_("submit")], ["Send".into()]);
+    /// if is_busy {
+    ///     submit = submit.with_attributes([class(["busy"]), disabled()]);
+    /// }
+    /// assert_eq!(submit.to_string(), r#"<button type="submit" class="busy" disabled>Send</button>"#);
+    /// ```
+    #[must_use]
+    #[doc(alias = "with_attrs")]
+    pub fn with_attributes(mut self, attrs: impl IntoIterator<Item = Attribute>) -> Self {
+        match &mut self.0 {
+            ElementInner::Parent { attributes, .. } | ElementInner::Void { attributes, .. } => {
+                attributes.extend(attrs);
+            }
+            ElementInner::Text(_)
+            | ElementInner::Script(_)
+            | ElementInner::Raw(_)
+            | ElementInner::Comment(_)
+            | ElementInner::Lazy(_)
+            | ElementInner::Multiple(_)
+            | ElementInner::None => (),
+        }
+        self
+    }
+
+    /// Append a child to the element
+    ///
+    /// The child is added after the existing children.
+    ///
+    /// Elements without a tag (text, raw HTML, comments, etc.) cannot have children,
+    /// so the child is rendered right after them instead, as with [`elt::fragment`].
+    ///
+    /// ## Panics
+    ///
+    /// Void elements cannot have children.
+    /// Appending to a void element panics in debug builds, and is a no-op in release builds.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::elt::{li, text, ul};
+    /// # let admin = true;
+    /// let mut list = ul([], [li([], [text("Home")])]);
+    /// if admin {
+    ///     list = list.with_child(li([], [text("Admin")]));
+    /// }
+    /// assert_eq!(list.to_string(), "<ul><li>Home</li><li>Admin</li></ul>");
+    /// ```
+    #[must_use]
+    pub fn with_child(self, child: Element) -> Self {
+        self.with_children([child])
+    }
+
+    /// Append children to the element
+    ///
+    /// See [`Element::with_child`]
+    #[must_use]
+    pub fn with_children(mut self, new_children: impl IntoIterator<Item = Element>) -> Self {
+        match &mut self.0 {
+            ElementInner::Parent { children, .. } | ElementInner::Multiple(children) => {
+                children.extend(new_children);
+                self
+            }
+            ElementInner::Void { tag, .. } => {
+                debug_assert!(false, "cannot append children to the void element '{tag}'");
+                self
+            }
+            ElementInner::None => new_children.into_iter().collect::<Vec<_>>().into(),
+            ElementInner::Text(_)
+            | ElementInner::Script(_)
+            | ElementInner::Raw(_)
+            | ElementInner::Comment(_)
+            | ElementInner::Lazy(_) => core::iter::once(self)
+                .chain(new_children)
+                .collect::<Vec<_>>()
+                .into(),
+        }
+    }
+
+    /// Tag name of the element, or `None` for elements without a tag (text, raw HTML, fragments, etc.)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::elt::{br, div, text};
+    /// assert_eq!(div([], []).tag(), Some("div"));
+    /// assert_eq!(br([]).tag(), Some("br"));
+    /// assert_eq!(text("hello").tag(), None);
+    /// ```
+    pub fn tag(&self) -> Option<&'static str> {
+        match &self.0 {
+            ElementInner::Parent { tag, .. } | ElementInner::Void { tag, .. } => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// Attributes of the element (empty for elements without a tag)
+    pub fn attributes(&self) -> &[Attribute] {
+        match &self.0 {
+            ElementInner::Parent { attributes, .. } | ElementInner::Void { attributes, .. } => {
+                attributes
+            }
+            _ => &[],
+        }
+    }
+
+    /// Mutable access to the attributes of the element, or `None` for elements without a tag
+    pub fn attributes_mut(&mut self) -> Option<&mut Vec<Attribute>> {
+        match &mut self.0 {
+            ElementInner::Parent { attributes, .. } | ElementInner::Void { attributes, .. } => {
+                Some(attributes)
+            }
+            _ => None,
+        }
+    }
+
+    /// Children of the element, or the elements of a fragment
+    ///
+    /// This is empty for other elements (void elements, text, raw HTML, etc.).
+    /// The content of [lazy](elt::lazy) elements is not accessible, as it is only built when rendered.
+    pub fn children(&self) -> &[Element] {
+        match &self.0 {
+            ElementInner::Parent { children, .. } | ElementInner::Multiple(children) => children,
+            _ => &[],
+        }
+    }
+
+    /// Transform every element of the tree with `f`, bottom-up
+    ///
+    /// The children are transformed before their parent, and the root element is transformed last.
+    /// [Lazy](elt::lazy) elements are passed to `f` as-is, without visiting their content.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{attr::class, elt::{div, table, text}};
+    /// let page = div([], [table([], []), text("a")]);
+    /// let page = page.map(|element| match element.tag() {
+    ///     Some("table") => element.with_attribute(class(["table"])),
+    ///     _ => element,
+    /// });
+    /// assert_eq!(page.to_string(), r#"<div><table class="table"></table>a</div>"#);
+    /// ```
+    pub fn map(self, f: impl Fn(Element) -> Element) -> Element {
+        self.map_with(&f)
+    }
+
+    fn map_with(mut self, f: &impl Fn(Element) -> Element) -> Element {
+        if let ElementInner::Parent { children, .. } | ElementInner::Multiple(children) =
+            &mut self.0
+        {
+            *children = core::mem::take(children)
+                .into_iter()
+                .map(|child| child.map_with(f))
+                .collect();
+        }
+        f(self)
+    }
+
+    /// Call `f` with a mutable reference to every element of the tree
+    ///
+    /// The order is the same as [`Self::map`]: the children are visited before their parent.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{AttributeValue, attr::href, elt::{a, p, text}};
+    /// let mut content = p([], [a([href("/about")], [text("About")])]);
+    /// content.visit_mut(|element| {
+    ///     for attribute in element.attributes_mut().into_iter().flatten() {
+    ///         if attribute.name() != Some("href") {
+    ///             continue;
+    ///         }
+    ///         if let Some(url) = attribute.value().and_then(AttributeValue::as_str) {
+    ///             *attribute = href(format!("https://example.com{url}"));
+    ///         }
+    ///     }
+    /// });
+    /// assert_eq!(content.to_string(), r#"<p><a href="https://example.com/about">About</a></p>"#);
+    /// ```
+    pub fn visit_mut(&mut self, mut f: impl FnMut(&mut Element)) {
+        self.visit_mut_with(&mut f);
+    }
+
+    fn visit_mut_with(&mut self, f: &mut impl FnMut(&mut Element)) {
+        if let ElementInner::Parent { children, .. } | ElementInner::Multiple(children) =
+            &mut self.0
+        {
+            for child in children {
+                child.visit_mut_with(f);
+            }
+        }
+        f(self);
+    }
+
+    fn is_parent_with_tag(&self, tag: &str) -> bool {
+        matches!(&self.0, ElementInner::Parent { tag: t, .. } if *t == tag)
+    }
+
+    /// Debug representation of the internal structure of the element (rather than the rendered HTML)
+    ///
+    /// This is mostly useful when working on the crate itself, the format is not stable.
+    pub fn inner_debug(&self) -> impl core::fmt::Debug + '_ {
+        InnerDebug(self)
+    }
+
+    /// Render the element into the given writer
+    ///
+    /// This writes the element piece by piece without building an intermediate `String`.
+    pub fn write_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        render::write_element(w, self)
+    }
+
+    /// Render the element as UTF-8 bytes
+    ///
+    /// This renders directly into a byte buffer whose capacity is reserved up front,
+    /// without building an intermediate `String`.
+    ///
+    /// See [`Document::into_bytes`]
+    pub fn render_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(render::estimate_capacity(self));
+        self.write_to(&mut render::BytesWriter(&mut buf)).unwrap();
+        buf
+    }
+
+    /// Append the rendered element to the given `String`
+    ///
+    /// See [`Document::render_into`]
+    pub fn render_into(&self, out: &mut String) {
+        out.reserve(render::estimate_capacity(self));
+        self.write_to(out).unwrap();
+    }
+
+    /// Render the element preceded by the `<!DOCTYPE html>` declaration
+    ///
+    /// This is useful to render a full page from an element, while `to_string` renders a fragment without doctype.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::elt::p;
+    /// let element = p([], ["Hello".into()]);
+    /// assert_eq!(element.to_string_with_doctype(), "<!DOCTYPE html>\n<p>Hello</p>");
+    /// assert_eq!(element.to_string(), "<p>Hello</p>");
+    /// ```
+    pub fn to_string_with_doctype(&self) -> String {
+        self.render_with(&RenderOptions::new().doctype(true))
+    }
+
+    /// Render the element with newlines and indentation, to make it easier to read
+    ///
+    /// See [`Document::to_string_pretty`]
+    #[doc(alias = "to_pretty_string")]
+    pub fn to_string_pretty(&self) -> String {
+        self.render_with(&RenderOptions::new().pretty(true))
+    }
+
+    /// Render XML-compatible markup
+    ///
+    /// This is a shortcut for `render_with(&RenderOptions::new().xhtml(true))`, see [`RenderOptions::xhtml`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{attr, elt::{br, input}};
+    /// assert_eq!(br([]).to_xhtml_string(), "<br />");
+    /// assert_eq!(input([attr::required()]).to_xhtml_string(), r#"<input required="required" />"#);
+    /// ```
+    pub fn to_xhtml_string(&self) -> String {
+        self.render_with(&RenderOptions::new().xhtml(true))
+    }
+
+    /// Render the element with the given [`RenderOptions`]
+    ///
+    /// The doctype is not rendered unless enabled with [`RenderOptions::doctype`].
+    pub fn render_with(&self, options: &RenderOptions) -> String {
+        let mut string = String::new();
+        if options.doctype_or(false) {
+            string.push_str(DOCTYPE);
+            string.push('\n');
+        }
+        render::write_element_with(&mut string, self, options).unwrap();
+        string
+    }
+
+    /// Render the element into the given [`std::io::Write`]
+    ///
+    /// This writes the element piece by piece without building an intermediate `String`.
+    /// As many small writes are issued, unbuffered writers (such as a `File` or a `TcpStream`)
+    /// should be wrapped in a [`std::io::BufWriter`].
+    ///
+    /// For asynchronous writers, see [`Document::render_chunks`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::elt::{li, ul};
+    /// let mut out = std::io::BufWriter::new(Vec::new());
+    /// ul([], [li([], ["a < b".into()])]).write_to_io(&mut out).unwrap();
+    /// assert_eq!(out.into_inner().unwrap(), b"<ul><li>a &lt; b</li></ul>");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to_io(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        render::io_adapter(w, |w| self.write_to(w))
+    }
 }
 
 fn assert_valid_tag_name(tag: &str) {
@@ -237,83 +1218,231 @@ impl From<ElementInner> for Element {
 
 impl Display for Element {
     fn fmt(&self, f: &mut alloc::fmt::Formatter<'_>) -> alloc::fmt::Result {
-        match &self.0 {
+        self.write_to(f)
+    }
+}
+
+/// Renders the element, same as `to_string`
+impl From<Element> for String {
+    fn from(value: Element) -> Self {
+        let mut string = String::new();
+        value.render_into(&mut string);
+        string
+    }
+}
+
+/// Shows the rendered HTML: compact with `{:?}`, and pretty-printed with `{:#?}`
+impl core::fmt::Debug for Document {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            self.write_prologue(f, true)?;
+            render::write_element_with(f, &self.html, &RenderOptions::new().pretty(true))
+        } else {
+            self.write_to(f)
+        }
+    }
+}
+
+/// Shows the rendered HTML: compact with `{:?}`, and pretty-printed with `{:#?}`
+///
+/// See [`Element::inner_debug`] to debug the internal structure.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::elt::{li, ul};
+/// let list = ul([], [li([], ["a".into()])]);
+/// assert_eq!(format!("{list:?}"), "<ul><li>a</li></ul>");
+/// assert_eq!(format!("{list:#?}"), "<ul>\n  <li>a</li>\n</ul>");
+/// ```
+impl core::fmt::Debug for Element {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            render::write_element_with(f, self, &RenderOptions::new().pretty(true))
+        } else {
+            self.write_to(f)
+        }
+    }
+}
+
+/// Shows the rendered attribute (e.g. `id="foo"`)
+///
+/// See [`Attribute::inner_debug`] to debug the internal structure.
+impl core::fmt::Debug for Attribute {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        render::write_attribute(f, self)
+    }
+}
+
+/// Structural debug representation of an [`Element`]
+struct InnerDebug<'a>(&'a Element);
+
+impl core::fmt::Debug for InnerDebug<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fn children(elements: &[Element]) -> Vec<InnerDebug<'_>> {
+            elements.iter().map(InnerDebug).collect()
+        }
+        match &self.0 .0 {
             ElementInner::Parent {
                 tag,
                 attributes,
-                children,
-            } => {
-                write!(f, "<{tag}")?;
-                write_attributes(f, attributes)?;
-                write!(f, ">")?;
-                for child in children {
-                    write!(f, "{child}")?;
-                }
-                write!(f, "</{tag}>")?;
-            }
-            ElementInner::Void { tag, attributes } => {
-                write!(f, "<{tag}")?;
-                write_attributes(f, attributes)?;
-                write!(f, ">")?;
-            }
-            ElementInner::Text(text) => write!(f, "{}", html_escape::encode_text(text))?,
-            ElementInner::Script(text) => write!(f, "{}", html_escape::encode_script(text))?,
-            ElementInner::Raw(raw) => write!(f, "{raw}")?,
-            ElementInner::Multiple(elems) => {
-                for elt in elems {
-                    write!(f, "{elt}")?;
-                }
-            }
-            ElementInner::None => (),
+                children: elements,
+            } => f
+                .debug_struct("Parent")
+                .field("tag", tag)
+                .field(
+                    "attributes",
+                    &attributes.iter().map(|a| &a.0).collect::<Vec<_>>(),
+                )
+                .field("children", &children(elements))
+                .finish(),
+            ElementInner::Void { tag, attributes } => f
+                .debug_struct("Void")
+                .field("tag", tag)
+                .field(
+                    "attributes",
+                    &attributes.iter().map(|a| &a.0).collect::<Vec<_>>(),
+                )
+                .finish(),
+            ElementInner::Text(text) => f.debug_tuple("Text").field(text).finish(),
+            ElementInner::Script(script) => f.debug_tuple("Script").field(script).finish(),
+            ElementInner::Raw(raw) => f.debug_tuple("Raw").field(raw).finish(),
+            ElementInner::Comment(comment) => f.debug_tuple("Comment").field(comment).finish(),
+            ElementInner::Lazy(lazy) => lazy.fmt(f),
+            ElementInner::Multiple(elements) => f
+                .debug_tuple("Multiple")
+                .field(&children(elements))
+                .finish(),
+            ElementInner::None => f.write_str("None"),
         }
-        Ok(())
     }
 }
 
-fn write_attributes(
-    f: &mut alloc::fmt::Formatter<'_>,
-    attributes: &[Attribute],
-) -> Result<(), alloc::fmt::Error> {
-    for attribute in attributes
-        .iter()
-        .filter(|a| !matches!(&a.0, AttributeInner::None))
-    {
-        write!(f, " {attribute}")?;
+impl Display for Attribute {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        render::write_attribute(f, self)
     }
-    Ok(())
 }
 
-impl Display for Attribute {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl AttributeValue {
+    /// The value as a string (before escaping), or `None` if it was not created from a string
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::AttributeValue;
+    /// assert_eq!(AttributeValue::from("a & b").as_str(), Some("a & b"));
+    /// assert_eq!(AttributeValue::from(3).as_str(), None);
+    /// ```
+    pub fn as_str(&self) -> Option<&str> {
         match &self.0 {
-            AttributeInner::KeyValue(key, value) => {
-                write!(
-                    f,
-                    "{}=\"{}\"",
-                    key,
-                    html_escape::encode_double_quoted_attribute(&value)
-                )
-            }
-            AttributeInner::KeyValueInt(key, value) => {
-                write!(f, "{key}=\"{value}\"")
-            }
-            AttributeInner::Flag(key) => write!(f, "{key}"),
-            AttributeInner::None => Ok(()),
+            AttributeValueInner::Text(value) | AttributeValueInner::Raw(value) => Some(value),
+            _ => None,
         }
     }
 }
 
+impl Display for AttributeValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        render::write_attribute_value(f, self)
+    }
+}
+
 impl Attribute {
+    /// Name of the attribute, or `None` for an attribute rendering nothing (such as [`attr::none`])
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::attr::{id, none, required};
+    /// assert_eq!(id("a").name(), Some("id"));
+    /// assert_eq!(required().name(), Some("required"));
+    /// assert_eq!(none().name(), None);
+    /// ```
+    pub fn name(&self) -> Option<&str> {
+        match &self.0 {
+            AttributeInner::KeyValue(name, _) | AttributeInner::Flag(name) => Some(name),
+            AttributeInner::None => None,
+        }
+    }
+
+    /// Value of the attribute, or `None` for a flag attribute
+    pub fn value(&self) -> Option<&AttributeValue> {
+        match &self.0 {
+            AttributeInner::KeyValue(_, value) => Some(value),
+            AttributeInner::Flag(_) | AttributeInner::None => None,
+        }
+    }
+
+    /// Debug representation of the internal structure of the attribute (rather than the rendered HTML)
+    ///
+    /// This is mostly useful when working on the crate itself, the format is not stable.
+    pub fn inner_debug(&self) -> impl core::fmt::Debug + '_ {
+        &self.0
+    }
+
     /// Create a new attribute
     pub fn new(name: &'static str, value: impl Into<Cow<'static, str>>) -> Self {
+        Self::new_value(name, value.into())
+    }
+
+    /// Create a new attribute from a string, or any type convertible into [`AttributeValue`]
+    pub fn new_value(name: &'static str, value: impl Into<AttributeValue>) -> Self {
         assert_valid_attribute_name(name);
         Self(AttributeInner::KeyValue(name.into(), value.into()))
     }
 
+    /// Create a new attribute if the value is `Some`
+    ///
+    /// Nothing is rendered if the value is `None` (like [`attr::none`])
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{Attribute, elt::input};
+    /// let email: Option<String> = None;
+    /// assert_eq!(input([Attribute::new_option("value", email)]).to_string(), "<input>");
+    /// ```
+    pub fn new_option(name: &'static str, value: Option<impl Into<AttributeValue>>) -> Self {
+        match value {
+            Some(value) => Self::new_value(name, value),
+            None => Self(AttributeInner::None),
+        }
+    }
+
+    /// Keep the attribute only if the condition is `true`
+    ///
+    /// The resulting `Option` is an iterator of attributes, and renders nothing when `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{Attribute, attr::class, elt::div};
+    /// let active = true;
+    /// assert_eq!(
+    ///     div(Attribute::maybe(active, class(["active"])), []).to_string(),
+    ///     r#"<div class="active"></div>"#,
+    /// );
+    /// assert_eq!(div(Attribute::maybe(false, class(["active"])), []).to_string(), "<div></div>");
+    /// ```
+    pub fn maybe(condition: bool, attr: Attribute) -> Option<Attribute> {
+        if condition {
+            Some(attr)
+        } else {
+            None
+        }
+    }
+
+    /// Create a new attribute with a name built at runtime from trusted parts
+    pub(crate) fn new_owned_name(name: String, value: impl Into<AttributeValue>) -> Self {
+        assert_valid_attribute_name(&name);
+        Self(AttributeInner::KeyValue(name.into(), value.into()))
+    }
+
     /// Create attribute with an integer value
     pub fn new_int(name: &'static str, value: i32) -> Self {
         assert_valid_attribute_name(name);
-        Self(AttributeInner::KeyValueInt(name.into(), value))
+        Self(AttributeInner::KeyValue(name.into(), value.into()))
     }
 
     /// Create a new flag attribute (that doesn't take a value)
@@ -322,6 +1451,27 @@ impl Attribute {
         Self(AttributeInner::Flag(name.into()))
     }
 
+    /// Create a new attribute with a value that is not escaped
+    ///
+    /// This **IS NOT SAFE** and should be avoided unless really necessary.
+    /// The value is rendered as-is between double quotes, so it must already be escaped.
+    /// Miss-use can lead to XSS vulnerability.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::Attribute;
+    /// assert_eq!(Attribute::new("title", "&copy;").to_string(), r#"title="&amp;copy;""#);
+    /// assert_eq!(Attribute::new_unsafe("title", "&copy;").to_string(), r#"title="&copy;""#);
+    /// ```
+    pub fn new_unsafe(name: &'static str, value: impl Into<Cow<'static, str>>) -> Self {
+        assert_valid_attribute_name(name);
+        Self(AttributeInner::KeyValue(
+            name.into(),
+            AttributeValue(AttributeValueInner::Raw(value.into())),
+        ))
+    }
+
     /// Create a new attribute with a name generated at runtime
     ///
     /// This **IS NOT SAFE** as the attribute name is not escaped.
@@ -332,7 +1482,10 @@ impl Attribute {
         name: impl Into<Cow<'static, str>>,
         value: impl Into<Cow<'static, str>>,
     ) -> Self {
-        Self(AttributeInner::KeyValue(name.into(), value.into()))
+        Self(AttributeInner::KeyValue(
+            name.into(),
+            AttributeValue(AttributeValueInner::Text(value.into())),
+        ))
     }
 }
 
@@ -343,6 +1496,51 @@ fn assert_valid_attribute_name(name: &str) {
     );
 }
 
+/// A reusable component which knows how to render itself as an [`Element`]
+///
+/// Every type implementing `Render` can be converted into an [`Element`],
+/// so that a component can be passed as a child with `.into()`.
+///
+/// Note that element constructors still take `impl IntoIterator<Item = Element>` children,
+/// as accepting `impl Into<Element>` items would break type inference of `["text".into()]`.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{Element, Render, attr::{alt, class, src}, elt::{div, img, li, span, ul}};
+/// struct UserCard {
+///     name: String,
+///     avatar: String,
+/// }
+///
+/// impl Render for UserCard {
+///     fn render(self) -> Element {
+///         div([class(["user-card"])], [
+///             img([src(self.avatar), alt("avatar")]),
+///             span([], [self.name.into()]),
+///         ])
+///     }
+/// }
+///
+/// let card = UserCard { name: "Alice".into(), avatar: "/alice.png".into() };
+/// let list = ul([], [li([], [card.into()])]);
+///
+/// assert_eq!(
+///     list.to_string(),
+///     r#"<ul><li><div class="user-card"><img src="/alice.png" alt="avatar"><span>Alice</span></div></li></ul>"#,
+/// );
+/// ```
+pub trait Render {
+    /// Create the element representing this component
+    fn render(self) -> Element;
+}
+
+impl<T: Render> From<T> for Element {
+    fn from(value: T) -> Self {
+        value.render()
+    }
+}
+
 impl IntoIterator for Element {
     type Item = Self;
     type IntoIter = core::iter::Once<Self>;
@@ -363,6 +1561,49 @@ impl From<Vec<Element>> for Element {
     }
 }
 
+/// Collect elements into a fragment (see [`elt::fragment`])
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{Element, elt::{td, tr}};
+/// let rows: Element = ["a", "b"].into_iter().map(|s| tr([], [td([], [s.into()])])).collect();
+/// assert_eq!(rows.to_string(), "<tr><td>a</td></tr><tr><td>b</td></tr>");
+/// ```
+impl FromIterator<Element> for Element {
+    fn from_iter<T: IntoIterator<Item = Element>>(iter: T) -> Self {
+        elt::fragment(iter)
+    }
+}
+
+/// Append elements, as with [`Element::with_children`]
+///
+/// For a fragment (or an element collected from an iterator) this adds siblings at the end of it.
+impl Extend<Element> for Element {
+    fn extend<T: IntoIterator<Item = Element>>(&mut self, iter: T) {
+        *self = core::mem::take(self).with_children(iter);
+    }
+}
+
+/// `None` renders nothing (same as [`elt::none`])
+///
+/// This allows to mix optional and mandatory elements in the same list of children.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::elt::{div, p, text};
+/// let banner = Some(p([], [text("Sale!")]));
+/// let no_banner = None;
+/// assert_eq!(div([], [banner.into(), text("content")]).to_string(), "<div><p>Sale!</p>content</div>");
+/// assert_eq!(div([], [no_banner.into(), text("content")]).to_string(), "<div>content</div>");
+/// ```
+impl From<Option<Element>> for Element {
+    fn from(value: Option<Element>) -> Self {
+        value.unwrap_or_default()
+    }
+}
+
 /// Create an HTML [`Document`]
 ///
 /// You must pass the [`elt::head`] and [`elt::body`] element as you would with any other element.
@@ -380,5 +1621,25 @@ pub fn html(
     attributes: impl IntoIterator<Item = Attribute>,
     children: impl IntoIterator<Item = Element>,
 ) -> Document {
-    Document(Element::new("html", attributes, children))
+    Document::from_html(elt::html_element(attributes, children))
+}
+
+/// Render a sequence of sibling elements to a `String`, without doctype nor `<html>` wrapper
+///
+/// This is useful to return partial HTML (for example in response to an htmx request)
+/// or to embed the result in another template.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{render_fragment, elt::{li, p}};
+/// let html = render_fragment([p([], ["Hello".into()]), li([], ["world".into()])]);
+/// assert_eq!(html, "<p>Hello</p><li>world</li>");
+/// ```
+pub fn render_fragment(elements: impl IntoIterator<Item = Element>) -> String {
+    let mut string = String::new();
+    for element in elements {
+        render::write_element(&mut string, &element).unwrap();
+    }
+    string
 }
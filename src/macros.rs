@@ -0,0 +1,186 @@
+/// Declarative syntax to build elements
+///
+/// It expands to calls to [`Element::new`](crate::Element::new), [`Element::new_void`](crate::Element::new_void),
+/// [`Attribute::new`](crate::Attribute::new) and [`elt::text`](crate::elt::text),
+/// so the result is exactly the same as building the elements with functions.
+///
+/// * `tag(attributes) { children }` or `tag { children }` creates an element
+/// * `tag(attributes);` or `tag;` creates a [void] element
+/// * `"literal"` creates an escaped text
+/// * `(expression)` embeds any expression implementing `Into<Element>`
+///
+/// Attributes are separated by commas and can be:
+///
+/// * `name = value` where the value is escaped and can be anything implementing `Into<AttributeValue>`
+/// * `name` for a flag attribute (such as `required`)
+/// * `"name" = value` or `"name"` for names that are not valid rust identifiers (such as `"hx-get"`)
+/// * `(expression)` embeds any expression implementing `Into<Attribute>`
+///
+/// A single root node expands to that element, several root nodes expand to a [fragment](crate::elt::fragment).
+///
+/// Requires the `macros` feature.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr, elt};
+/// let dynamic_text = String::from("a < b");
+/// let card = fun_html::html! {
+///     div(class = "card", "data-id" = 42) {
+///         h1 { "Title" }
+///         p { (dynamic_text) }
+///         input(type = "text", (attr::name("q")), required);
+///     }
+/// };
+/// assert_eq!(
+///     card.to_string(),
+///     r#"<div class="card" data-id="42"><h1>Title</h1><p>a &lt; b</p><input type="text" name="q" required></div>"#,
+/// );
+/// ```
+///
+/// [void]: https://developer.mozilla.org/en-US/docs/Glossary/Void_element
+#[macro_export]
+macro_rules! html {
+    ($($input:tt)*) => {
+        $crate::__html!(@nodes root [] $($input)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __html {
+    (@nodes root [$node:expr]) => {
+        $node
+    };
+    (@nodes root [$($node:expr),*]) => {
+        $crate::elt::fragment([$($node),*])
+    };
+    (@nodes list [$($node:expr),*]) => {
+        [$($node),*]
+    };
+    (@nodes $mode:ident [$($node:expr),*] $text:literal $($rest:tt)*) => {
+        $crate::__html!(@nodes $mode [$($node,)* $crate::elt::text($text)] $($rest)*)
+    };
+    (@nodes $mode:ident [$($node:expr),*] ($element:expr) $($rest:tt)*) => {
+        $crate::__html!(@nodes $mode [$($node,)* $crate::Element::from($element)] $($rest)*)
+    };
+    (@nodes $mode:ident [$($node:expr),*] $tag:ident ($($attrs:tt)*) { $($children:tt)* } $($rest:tt)*) => {
+        $crate::__html!(@nodes $mode [$($node,)* $crate::Element::new(
+            stringify!($tag),
+            $crate::__html!(@attrs [] $($attrs)*),
+            $crate::__html!(@nodes list [] $($children)*),
+        )] $($rest)*)
+    };
+    (@nodes $mode:ident [$($node:expr),*] $tag:ident ($($attrs:tt)*); $($rest:tt)*) => {
+        $crate::__html!(@nodes $mode [$($node,)* $crate::Element::new_void(
+            stringify!($tag),
+            $crate::__html!(@attrs [] $($attrs)*),
+        )] $($rest)*)
+    };
+    (@nodes $mode:ident [$($node:expr),*] $tag:ident { $($children:tt)* } $($rest:tt)*) => {
+        $crate::__html!(@nodes $mode [$($node,)* $crate::Element::new(
+            stringify!($tag),
+            [],
+            $crate::__html!(@nodes list [] $($children)*),
+        )] $($rest)*)
+    };
+    (@nodes $mode:ident [$($node:expr),*] $tag:ident; $($rest:tt)*) => {
+        $crate::__html!(@nodes $mode [$($node,)* $crate::Element::new_void(stringify!($tag), [])] $($rest)*)
+    };
+
+    (@attrs [$($attr:expr),*]) => {
+        [$($attr),*]
+    };
+    (@attrs [$($attr:expr),*] $name:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__html!(@attrs [$($attr,)* $crate::Attribute::new_value(stringify!($name), $value)] $($($rest)*)?)
+    };
+    (@attrs [$($attr:expr),*] $name:literal = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__html!(@attrs [$($attr,)* $crate::Attribute::new_value($name, $value)] $($($rest)*)?)
+    };
+    (@attrs [$($attr:expr),*] ($value:expr) $(, $($rest:tt)*)?) => {
+        $crate::__html!(@attrs [$($attr,)* $crate::Attribute::from($value)] $($($rest)*)?)
+    };
+    (@attrs [$($attr:expr),*] $name:ident $(, $($rest:tt)*)?) => {
+        $crate::__html!(@attrs [$($attr,)* $crate::Attribute::new_flag(stringify!($name))] $($($rest)*)?)
+    };
+    (@attrs [$($attr:expr),*] $name:literal $(, $($rest:tt)*)?) => {
+        $crate::__html!(@attrs [$($attr,)* $crate::Attribute::new_flag($name)] $($($rest)*)?)
+    };
+}
+
+/// Build a `Vec<Attribute>` from a list of attributes, some of them being conditional
+///
+/// Items are separated by commas and can be:
+///
+/// * `expression`: any expression implementing `Into<Attribute>`
+/// * `if condition => expression`: the attribute is only added if the condition is `true`
+/// * `name ? condition`: the flag attribute `name` is only added if the condition is `true`
+///
+/// The result can be used anywhere an `impl IntoIterator<Item = Attribute>` is expected.
+///
+/// Requires the `macros` feature.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attrs, attr::{class, id}, elt::button};
+/// let primary = true;
+/// let is_disabled = false;
+/// let element = button(
+///     attrs![id("x"), class(["btn"]), if primary => class(["btn-primary"]), disabled ? is_disabled],
+///     ["Click".into()],
+/// );
+/// assert_eq!(element.to_string(), r#"<button id="x" class="btn btn-primary">Click</button>"#);
+/// ```
+#[macro_export]
+macro_rules! attrs {
+    ($($input:tt)*) => {{
+        #[allow(unused_mut)]
+        let mut attributes = $crate::__private::Vec::<$crate::Attribute>::new();
+        $crate::__attrs!(@push attributes $($input)*);
+        attributes
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __attrs {
+    (@push $attributes:ident) => {};
+    (@push $attributes:ident if $condition:expr => $attr:expr $(, $($rest:tt)*)?) => {
+        if $condition {
+            $attributes.push($crate::Attribute::from($attr));
+        }
+        $crate::__attrs!(@push $attributes $($($rest)*)?);
+    };
+    (@push $attributes:ident $name:ident ? $condition:expr $(, $($rest:tt)*)?) => {
+        if $condition {
+            $attributes.push($crate::Attribute::new_flag(stringify!($name)));
+        }
+        $crate::__attrs!(@push $attributes $($($rest)*)?);
+    };
+    (@push $attributes:ident $attr:expr $(, $($rest:tt)*)?) => {
+        $attributes.push($crate::Attribute::from($attr));
+        $crate::__attrs!(@push $attributes $($($rest)*)?);
+    };
+}
+
+/// Escaped text, formatted with the same syntax as [`format!`]
+///
+/// This is a shortcut for `elt::text(format!(...))`,
+/// which does not allocate when there is nothing to interpolate.
+///
+/// Requires the `macros` feature.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{text, elt::p};
+/// let name = "<Jo>";
+/// assert_eq!(p([], [text!("Hello, {name}!")]).to_string(), "<p>Hello, &lt;Jo&gt;!</p>");
+/// ```
+#[macro_export]
+macro_rules! text {
+    ($($arg:tt)*) => {
+        $crate::__private::text_from_args(::core::format_args!($($arg)*))
+    };
+}
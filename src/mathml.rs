@@ -0,0 +1,125 @@
+//! [MathML](https://developer.mozilla.org/en-US/docs/Web/MathML) elements and attributes
+//!
+//! Text inside elements such as [`mi`], [`mn`] and [`mo`] is escaped as usual.
+//!
+//! ## Example
+//!
+//! ```
+//! use fun_html::mathml::{display, math, mfrac, mi, mn, MathDisplay};
+//!
+//! let half = math([display(MathDisplay::Inline)], [mfrac([], [mn([], ["1".into()]), mi([], ["x".into()])])]);
+//!
+//! assert_eq!(
+//!     half.to_string(),
+//!     r#"<math xmlns="http://www.w3.org/1998/Math/MathML" display="inline"><mfrac><mn>1</mn><mi>x</mi></mfrac></math>"#,
+//! );
+//! ```
+
+use crate::{Attribute, Element};
+
+/// Namespace of MathML elements, set by [`math`]
+pub const NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
+/// `<math>` root element, with the MathML namespace (`xmlns`) attribute
+pub fn math(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new(
+        "math",
+        core::iter::once(Attribute::new("xmlns", NAMESPACE)).chain(attributes),
+        children,
+    )
+}
+
+/// `<mrow>` (grouped sub-expressions)
+pub fn mrow(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("mrow", attributes, children)
+}
+
+/// `<mi>` (identifier)
+pub fn mi(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("mi", attributes, children)
+}
+
+/// `<mn>` (number)
+pub fn mn(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("mn", attributes, children)
+}
+
+/// `<mo>` (operator)
+pub fn mo(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("mo", attributes, children)
+}
+
+/// `<mfrac>` (fraction, the first child being the numerator and the second the denominator)
+pub fn mfrac(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("mfrac", attributes, children)
+}
+
+/// `<msup>` (superscript, the first child being the base and the second the exponent)
+pub fn msup(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("msup", attributes, children)
+}
+
+/// `<msub>` (subscript, the first child being the base and the second the subscript)
+pub fn msub(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("msub", attributes, children)
+}
+
+/// `<msqrt>` (square root)
+pub fn msqrt(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("msqrt", attributes, children)
+}
+
+/// `<mtext>` (text)
+pub fn mtext(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("mtext", attributes, children)
+}
+
+/// Presentation of a `<math>` element, for the [`display`] attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathDisplay {
+    /// `block`: rendered on its own line
+    Block,
+    /// `inline`: rendered within the surrounding text
+    Inline,
+}
+
+/// `display` attribute (for `<math>`)
+pub fn display(value: MathDisplay) -> Attribute {
+    Attribute::new(
+        "display",
+        match value {
+            MathDisplay::Block => "block",
+            MathDisplay::Inline => "inline",
+        },
+    )
+}
@@ -0,0 +1,733 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+use crate::{
+    escape, Attribute, AttributeInner, AttributeValue, AttributeValueInner, Element, ElementInner,
+};
+
+/// Options controlling how a [`Document`](crate::Document) or an [`Element`] is rendered
+///
+/// The default options reproduce the output of the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{RenderOptions, elt::{div, hr}};
+/// let element = div([], [hr([])]);
+/// let options = RenderOptions::new().pretty(true).indent_width(4).self_close_void(true);
+/// assert_eq!(element.render_with(&options), "<div>\n    <hr />\n</div>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderOptions {
+    pretty: bool,
+    indent_width: usize,
+    self_close_void: bool,
+    xhtml: bool,
+    doctype: Option<bool>,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderOptions {
+    /// Create the default options (compact output, same as the `Display` implementations)
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            pretty: false,
+            indent_width: 2,
+            self_close_void: false,
+            xhtml: false,
+            doctype: None,
+        }
+    }
+
+    /// Render block-level children on their own line, indented (default: `false`)
+    ///
+    /// See [`Document::to_string_pretty`](crate::Document::to_string_pretty)
+    #[must_use]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Number of spaces per indentation level when rendering pretty (default: `2`)
+    #[must_use]
+    pub fn indent_width(mut self, indent_width: usize) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    /// Render void elements as `<br />` instead of `<br>` (default: `false`)
+    #[must_use]
+    pub fn self_close_void(mut self, self_close_void: bool) -> Self {
+        self.self_close_void = self_close_void;
+        self
+    }
+
+    /// Render XML-compatible markup, as needed for XHTML, EPUB or some email pipelines (default: `false`)
+    ///
+    /// Void elements are self-closed (`<br />`) and flag attributes are given a value (`checked="checked"`).
+    /// Everything else is rendered identically.
+    ///
+    /// Note that raw elements (such as [`elt::raw`](crate::elt::raw)) are rendered untouched,
+    /// so it is up to the caller to make sure their content is valid XML.
+    #[must_use]
+    pub fn xhtml(mut self, xhtml: bool) -> Self {
+        self.xhtml = xhtml;
+        self
+    }
+
+    /// Whether to render the `<!DOCTYPE html>` declaration
+    ///
+    /// By default, the doctype is rendered for a [`Document`](crate::Document) but not for an [`Element`].
+    #[must_use]
+    pub fn doctype(mut self, doctype: bool) -> Self {
+        self.doctype = Some(doctype);
+        self
+    }
+
+    pub(crate) fn doctype_or(&self, default: bool) -> bool {
+        self.doctype.unwrap_or(default)
+    }
+}
+
+const DEFAULT_OPTIONS: RenderOptions = RenderOptions::new();
+
+pub(crate) fn write_element(w: &mut impl Write, element: &Element) -> fmt::Result {
+    write_element_compact(w, element, &DEFAULT_OPTIONS)
+}
+
+pub(crate) fn write_element_with(
+    w: &mut impl Write,
+    element: &Element,
+    options: &RenderOptions,
+) -> fmt::Result {
+    if options.pretty {
+        write_element_pretty(w, element, options, 0)
+    } else {
+        write_element_compact(w, element, options)
+    }
+}
+
+fn write_element_compact(
+    w: &mut impl Write,
+    element: &Element,
+    options: &RenderOptions,
+) -> fmt::Result {
+    match &element.0 {
+        ElementInner::Parent {
+            tag,
+            attributes,
+            children,
+        } => {
+            write_open_tag(w, tag, attributes, options)?;
+            for child in children {
+                write_element_compact(w, child, options)?;
+            }
+            write_close_tag(w, tag)
+        }
+        ElementInner::Void { tag, attributes } => write_void_tag(w, tag, attributes, options),
+        ElementInner::Text(text) => escape::write_text(w, text),
+        ElementInner::Script(script) => escape::write_script(w, script),
+        ElementInner::Raw(raw) => w.write_str(raw),
+        ElementInner::Comment(comment) => {
+            w.write_str("<!--")?;
+            w.write_str(comment)?;
+            w.write_str("-->")
+        }
+        ElementInner::Lazy(lazy) => write_element_compact(w, &lazy.build(), options),
+        ElementInner::Multiple(elements) => {
+            for element in elements {
+                write_element_compact(w, element, options)?;
+            }
+            Ok(())
+        }
+        ElementInner::None => Ok(()),
+    }
+}
+
+/// Render with newlines and indentation between block-level children
+///
+/// The content of whitespace-sensitive elements (`pre`, `textarea`, `script` and `style`) is rendered untouched.
+fn write_element_pretty(
+    w: &mut impl Write,
+    element: &Element,
+    options: &RenderOptions,
+    depth: usize,
+) -> fmt::Result {
+    match &element.0 {
+        ElementInner::Parent {
+            tag,
+            attributes,
+            children,
+        } => {
+            write_open_tag(w, tag, attributes, options)?;
+            if is_whitespace_sensitive(tag) {
+                for child in children {
+                    write_element_compact(w, child, options)?;
+                }
+            } else {
+                write_children_pretty(w, children, options, depth + 1, true)?;
+                if children.iter().any(is_block) {
+                    write_new_line(w, options, depth)?;
+                }
+            }
+            write_close_tag(w, tag)
+        }
+        ElementInner::Multiple(elements) => {
+            write_children_pretty(w, elements, options, depth, false)
+        }
+        ElementInner::Lazy(lazy) => write_element_pretty(w, &lazy.build(), options, depth),
+        _ => write_element_compact(w, element, options),
+    }
+}
+
+/// Write the children on their own lines if there is any block-level child, inline otherwise
+fn write_children_pretty(
+    w: &mut impl Write,
+    children: &[Element],
+    options: &RenderOptions,
+    depth: usize,
+    leading_new_line: bool,
+) -> fmt::Result {
+    if !children.iter().any(is_block) {
+        return children
+            .iter()
+            .try_for_each(|child| write_element_pretty(w, child, options, depth));
+    }
+    let mut new_line = leading_new_line;
+    for_each_flattened(children, &mut |child| {
+        if new_line {
+            write_new_line(w, options, depth)?;
+        }
+        new_line = true;
+        write_element_pretty(w, child, options, depth)
+    })
+}
+
+/// Visit the elements, flattening fragments and skipping empty elements
+fn for_each_flattened(
+    elements: &[Element],
+    f: &mut impl FnMut(&Element) -> fmt::Result,
+) -> fmt::Result {
+    for element in elements {
+        match &element.0 {
+            ElementInner::Multiple(elements) => for_each_flattened(elements, f)?,
+            ElementInner::None => (),
+            _ => f(element)?,
+        }
+    }
+    Ok(())
+}
+
+fn write_new_line(w: &mut impl Write, options: &RenderOptions, depth: usize) -> fmt::Result {
+    w.write_char('\n')?;
+    for _ in 0..depth * options.indent_width {
+        w.write_char(' ')?;
+    }
+    Ok(())
+}
+
+fn is_block(element: &Element) -> bool {
+    match &element.0 {
+        ElementInner::Parent { tag, .. } | ElementInner::Void { tag, .. } => {
+            BLOCK_TAGS.contains(tag)
+        }
+        ElementInner::Multiple(elements) => elements.iter().any(is_block),
+        _ => false,
+    }
+}
+
+fn is_whitespace_sensitive(tag: &str) -> bool {
+    matches!(tag, "pre" | "textarea" | "script" | "style")
+}
+
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "body",
+    "details",
+    "dialog",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "meta",
+    "nav",
+    "noscript",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "picture",
+    "pre",
+    "script",
+    "section",
+    "select",
+    "source",
+    "style",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "template",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "ul",
+];
+
+pub(crate) fn write_open_tag(
+    w: &mut impl Write,
+    tag: &str,
+    attributes: &[Attribute],
+    options: &RenderOptions,
+) -> fmt::Result {
+    write_tag_start(w, tag, attributes, options)?;
+    w.write_char('>')
+}
+
+fn write_void_tag(
+    w: &mut impl Write,
+    tag: &str,
+    attributes: &[Attribute],
+    options: &RenderOptions,
+) -> fmt::Result {
+    write_tag_start(w, tag, attributes, options)?;
+    if options.self_close_void || options.xhtml {
+        w.write_str(" />")
+    } else {
+        w.write_char('>')
+    }
+}
+
+fn write_tag_start(
+    w: &mut impl Write,
+    tag: &str,
+    attributes: &[Attribute],
+    options: &RenderOptions,
+) -> fmt::Result {
+    w.write_char('<')?;
+    w.write_str(tag)?;
+    let mut occurrences = Occurrences::new();
+    for (i, attribute) in attributes.iter().enumerate() {
+        if let Some(name) = attribute.name() {
+            occurrences.record(name, i);
+        }
+    }
+    for occurrence in occurrences.as_slice() {
+        w.write_char(' ')?;
+        if occurrence.first == occurrence.last {
+            write_attribute_with(w, &attributes[occurrence.first], options)?;
+        } else if let Some(separator) = merge_separator(occurrence.name) {
+            let repeated = &attributes[occurrence.first..=occurrence.last];
+            write_merged_attribute(w, occurrence.name, separator, repeated, options)?;
+        } else {
+            write_attribute_with(w, &attributes[occurrence.last], options)?;
+        }
+    }
+    Ok(())
+}
+
+/// Number of distinct attribute names tracked without allocating
+const INLINE_OCCURRENCES: usize = 16;
+
+/// First and last index of the attributes with the same name on an element
+#[derive(Clone, Copy)]
+struct Occurrence<'a> {
+    name: &'a str,
+    first: usize,
+    last: usize,
+}
+
+/// Occurrences of the attribute names of an element, in order of first occurrence
+///
+/// Elements with up to [`INLINE_OCCURRENCES`] distinct names are tracked on the stack,
+/// larger ones fall back to a `BTreeMap` lookup.
+struct Occurrences<'a> {
+    inline: [Occurrence<'a>; INLINE_OCCURRENCES],
+    len: usize,
+    spilled: Vec<Occurrence<'a>>,
+    positions: BTreeMap<&'a str, usize>,
+}
+
+impl<'a> Occurrences<'a> {
+    fn new() -> Self {
+        Self {
+            inline: [Occurrence {
+                name: "",
+                first: 0,
+                last: 0,
+            }; INLINE_OCCURRENCES],
+            len: 0,
+            spilled: Vec::new(),
+            positions: BTreeMap::new(),
+        }
+    }
+
+    fn record(&mut self, name: &'a str, index: usize) {
+        let occurrence = Occurrence {
+            name,
+            first: index,
+            last: index,
+        };
+        if self.spilled.is_empty() {
+            if let Some(existing) = self.inline[..self.len].iter_mut().find(|o| o.name == name) {
+                existing.last = index;
+            } else if self.len < INLINE_OCCURRENCES {
+                self.inline[self.len] = occurrence;
+                self.len += 1;
+            } else {
+                self.spilled.extend_from_slice(&self.inline);
+                for (position, o) in self.spilled.iter().enumerate() {
+                    self.positions.insert(o.name, position);
+                }
+                self.positions.insert(name, self.spilled.len());
+                self.spilled.push(occurrence);
+            }
+        } else if let Some(&position) = self.positions.get(name) {
+            self.spilled[position].last = index;
+        } else {
+            self.positions.insert(name, self.spilled.len());
+            self.spilled.push(occurrence);
+        }
+    }
+
+    fn as_slice(&self) -> &[Occurrence<'a>] {
+        if self.spilled.is_empty() {
+            &self.inline[..self.len]
+        } else {
+            &self.spilled
+        }
+    }
+}
+
+/// Separator used to merge the values of repeated attributes (other attributes are last-wins)
+fn merge_separator(name: &str) -> Option<&'static str> {
+    match name {
+        "class" => Some(" "),
+        "style" => Some("; "),
+        _ => None,
+    }
+}
+
+/// Write the non-empty values of all the attributes named `name`, joined by `separator`
+fn write_merged_attribute(
+    w: &mut impl Write,
+    name: &str,
+    separator: &str,
+    attributes: &[Attribute],
+    options: &RenderOptions,
+) -> fmt::Result {
+    let values = attributes.iter().filter_map(|a| match &a.0 {
+        AttributeInner::KeyValue(key, value) if key == name => Some(value),
+        _ => None,
+    });
+    let mut values = values
+        .filter(|value| match &value.0 {
+            AttributeValueInner::Text(text) | AttributeValueInner::Raw(text) => {
+                !trim_value(text, separator).is_empty()
+            }
+            _ => true,
+        })
+        .peekable();
+    if values.peek().is_none() {
+        // Nothing to merge (only empty values or flags)
+        let last = attributes.iter().rev().find(|a| a.name() == Some(name));
+        return write_attribute_with(w, last.unwrap_or(&attributes[0]), options);
+    }
+    w.write_str(name)?;
+    w.write_str("=\"")?;
+    let mut first = true;
+    for value in values {
+        if !first {
+            w.write_str(separator)?;
+        }
+        first = false;
+        match &value.0 {
+            AttributeValueInner::Text(text) => {
+                escape::write_attribute_value(w, trim_value(text, separator))?;
+            }
+            AttributeValueInner::Raw(raw) => w.write_str(trim_value(raw, separator))?,
+            _ => write_attribute_value(w, value)?,
+        }
+    }
+    w.write_char('"')
+}
+
+/// Trim the surrounding whitespace, as well as a trailing separator (such as `;` for styles)
+fn trim_value<'a>(value: &'a str, separator: &str) -> &'a str {
+    let value = value.trim();
+    let terminator = separator.trim();
+    if terminator.is_empty() {
+        value
+    } else {
+        value.trim_end_matches(terminator).trim_end()
+    }
+}
+
+pub(crate) fn write_close_tag(w: &mut impl Write, tag: &str) -> fmt::Result {
+    w.write_str("</")?;
+    w.write_str(tag)?;
+    w.write_char('>')
+}
+
+pub(crate) fn write_attribute(w: &mut impl Write, attribute: &Attribute) -> fmt::Result {
+    write_attribute_with(w, attribute, &DEFAULT_OPTIONS)
+}
+
+fn write_attribute_with(
+    w: &mut impl Write,
+    attribute: &Attribute,
+    options: &RenderOptions,
+) -> fmt::Result {
+    match &attribute.0 {
+        AttributeInner::KeyValue(key, value) => {
+            w.write_str(key)?;
+            w.write_str("=\"")?;
+            write_attribute_value(w, value)?;
+            w.write_char('"')
+        }
+        AttributeInner::Flag(key) if options.xhtml => {
+            w.write_str(key)?;
+            w.write_str("=\"")?;
+            w.write_str(key)?;
+            w.write_char('"')
+        }
+        AttributeInner::Flag(key) => w.write_str(key),
+        AttributeInner::None => Ok(()),
+    }
+}
+
+pub(crate) fn write_attribute_value(w: &mut impl Write, value: &AttributeValue) -> fmt::Result {
+    match &value.0 {
+        AttributeValueInner::Text(text) => escape::write_attribute_value(w, text),
+        AttributeValueInner::Raw(raw) => w.write_str(raw),
+        AttributeValueInner::Int(value) => write!(w, "{value}"),
+        AttributeValueInner::UInt(value) => write!(w, "{value}"),
+        AttributeValueInner::Float(value) => write!(w, "{value}"),
+        AttributeValueInner::Bool(value) => write!(w, "{value}"),
+        AttributeValueInner::Char(value) => {
+            escape::write_attribute_value(w, value.encode_utf8(&mut [0; 4]))
+        }
+    }
+}
+
+/// Iterator rendering an element lazily in chunks of bounded length
+///
+/// Each step of the walk renders a single piece (an opening tag, a text node or a closing tag),
+/// and pieces are accumulated until adding the next one would exceed the target chunk length.
+/// Hence a chunk is only longer than the target when it consists of a single oversized piece.
+pub(crate) struct Chunks {
+    stack: Vec<Step>,
+    chunk_len: usize,
+    carry: String,
+    scratch: String,
+}
+
+enum Step {
+    Element(Element),
+    Close(&'static str),
+}
+
+impl Chunks {
+    pub(crate) fn new(prefix: String, element: Element, chunk_len: usize) -> Self {
+        Self {
+            stack: Vec::from([Step::Element(element)]),
+            chunk_len,
+            carry: prefix,
+            scratch: String::new(),
+        }
+    }
+
+    /// Render the next piece, returning `false` when the walk is over
+    fn write_next_piece(&mut self, w: &mut String) -> bool {
+        while let Some(step) = self.stack.pop() {
+            let element = match step {
+                Step::Element(element) => element,
+                Step::Close(tag) => {
+                    write_close_tag(w, tag).unwrap();
+                    return true;
+                }
+            };
+            match element.0 {
+                ElementInner::Parent {
+                    tag,
+                    attributes,
+                    children,
+                } => {
+                    write_open_tag(w, tag, &attributes, &DEFAULT_OPTIONS).unwrap();
+                    self.stack.push(Step::Close(tag));
+                    self.stack
+                        .extend(children.into_iter().rev().map(Step::Element));
+                }
+                ElementInner::Void { tag, attributes } => {
+                    write_open_tag(w, tag, &attributes, &DEFAULT_OPTIONS).unwrap();
+                }
+                ElementInner::Multiple(elements) => {
+                    self.stack
+                        .extend(elements.into_iter().rev().map(Step::Element));
+                    continue;
+                }
+                ElementInner::Lazy(lazy) => {
+                    self.stack.push(Step::Element(lazy.build()));
+                    continue;
+                }
+                ElementInner::None => continue,
+                inner => write_element(w, &Element(inner)).unwrap(),
+            }
+            return true;
+        }
+        false
+    }
+}
+
+impl Iterator for Chunks {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = core::mem::take(&mut self.carry);
+        while chunk.is_empty() || chunk.len() < self.chunk_len {
+            let mut piece = core::mem::take(&mut self.scratch);
+            piece.clear();
+            if !self.write_next_piece(&mut piece) {
+                break;
+            }
+            if !chunk.is_empty() && chunk.len() + piece.len() > self.chunk_len {
+                self.carry = piece;
+                break;
+            }
+            chunk.push_str(&piece);
+            self.scratch = piece;
+        }
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+}
+
+/// Capacity to reserve before rendering the element
+///
+/// This is the estimated length plus some room for the characters expanded by escaping.
+pub(crate) fn estimate_capacity(element: &Element) -> usize {
+    let estimate = estimate_len(element);
+    estimate + estimate / 8
+}
+
+/// Estimate the length of the rendered element, ignoring the bytes added by escaping
+fn estimate_len(element: &Element) -> usize {
+    match &element.0 {
+        ElementInner::Parent {
+            tag,
+            attributes,
+            children,
+        } => {
+            estimate_open_tag_len(tag, attributes)
+                + children.iter().map(estimate_len).sum::<usize>()
+                + tag.len()
+                + 3
+        }
+        ElementInner::Void { tag, attributes } => estimate_open_tag_len(tag, attributes),
+        ElementInner::Text(text) | ElementInner::Script(text) | ElementInner::Raw(text) => {
+            text.len()
+        }
+        ElementInner::Comment(comment) => comment.len() + 7,
+        // Not built before it is rendered
+        ElementInner::Lazy(_) => 0,
+        ElementInner::Multiple(elements) => elements.iter().map(estimate_len).sum(),
+        ElementInner::None => 0,
+    }
+}
+
+fn estimate_open_tag_len(tag: &str, attributes: &[Attribute]) -> usize {
+    let attributes_len: usize = attributes
+        .iter()
+        .map(|attribute| match &attribute.0 {
+            AttributeInner::KeyValue(key, value) => {
+                let value_len = match &value.0 {
+                    AttributeValueInner::Text(text) | AttributeValueInner::Raw(text) => text.len(),
+                    AttributeValueInner::Char(value) => value.len_utf8(),
+                    _ => 4,
+                };
+                key.len() + value_len + 4
+            }
+            AttributeInner::Flag(key) => key.len() + 1,
+            AttributeInner::None => 0,
+        })
+        .sum();
+    tag.len() + attributes_len + 2
+}
+
+/// [`fmt::Write`] appending the UTF-8 bytes to a `Vec<u8>`
+pub(crate) struct BytesWriter<'a>(pub(crate) &'a mut Vec<u8>);
+
+impl Write for BytesWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+/// Run `render` with a [`fmt::Write`] that forwards to the given [`std::io::Write`]
+#[cfg(feature = "std")]
+pub(crate) fn io_adapter(
+    w: &mut impl std::io::Write,
+    render: impl FnOnce(&mut IoWriter<'_, '_>) -> fmt::Result,
+) -> std::io::Result<()> {
+    let mut writer = IoWriter {
+        inner: w,
+        error: None,
+    };
+    match render(&mut writer) {
+        Ok(()) => Ok(()),
+        Err(fmt::Error) => Err(writer
+            .error
+            .unwrap_or_else(|| std::io::Error::new(std::io::ErrorKind::Other, fmt::Error))),
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) struct IoWriter<'a, 'w> {
+    inner: &'a mut (dyn std::io::Write + 'w),
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl Write for IoWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
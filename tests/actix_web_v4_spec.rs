@@ -0,0 +1,52 @@
+#![cfg(feature = "actix_web_v4")]
+
+use actix_web_v4::{
+    body::{to_bytes, BoxBody},
+    http::StatusCode,
+    test::TestRequest,
+    Responder,
+};
+
+use fun_html::{
+    attr::id,
+    elt::{body, div, head},
+    html,
+};
+
+async fn respond(responder: impl Responder<Body = BoxBody>) -> (StatusCode, String, String) {
+    let response = responder.respond_to(&TestRequest::default().to_http_request());
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get("content-type")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_owned();
+    let body = to_bytes(response.into_body()).await.ok().unwrap();
+    (
+        status,
+        content_type,
+        String::from_utf8(body.to_vec()).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn document_should_implement_responder() {
+    let (status, content_type, body) =
+        respond(html([], [head([], []), body([], ["hello".into()])])).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "text/html; charset=utf-8");
+    assert_eq!(
+        body,
+        "<!DOCTYPE html>\n<html><head></head><body>hello</body></html>"
+    );
+}
+
+#[tokio::test]
+async fn element_should_implement_responder() {
+    let (status, content_type, body) = respond(div([id("foo")], ["hello".into()])).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "text/html; charset=utf-8");
+    assert_eq!(body, "<div id=\"foo\">hello</div>");
+}
@@ -0,0 +1,82 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Write,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use fun_html::{attr, elt, Attribute};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if COUNTING.with(Cell::get) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Discards the output, only counting the number of bytes written
+struct Sink(usize);
+
+impl Write for Sink {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+#[test]
+fn write_to_should_not_allocate() {
+    let doc = fun_html::html(
+        [attr::lang("en")],
+        [
+            elt::head(
+                [],
+                [
+                    elt::title([], "Tom & Jerry"),
+                    elt::script([], "alert('</script>')"),
+                ],
+            ),
+            elt::body(
+                [attr::class(["a", "b"])],
+                [elt::ul(
+                    [
+                        Attribute::new_value("data-count", 2),
+                        Attribute::new_value("data-ratio", 0.5),
+                    ],
+                    [
+                        elt::li(
+                            [Attribute::new("title", "\"quoted\"")],
+                            ["<escaped>".into()],
+                        ),
+                        elt::li([], [elt::raw("<b>raw</b>")]),
+                    ],
+                )],
+            ),
+        ],
+    );
+    let mut sink = Sink(0);
+
+    COUNTING.with(|c| c.set(true));
+    doc.write_to(&mut sink).unwrap();
+    COUNTING.with(|c| c.set(false));
+
+    assert_eq!(ALLOCATIONS.load(Ordering::Relaxed), 0);
+    assert_eq!(sink.0, doc.to_string().len());
+}
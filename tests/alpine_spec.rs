@@ -0,0 +1,30 @@
+#![cfg(feature = "alpine")]
+
+use rstest::rstest;
+
+use fun_html::{attr::alpine, Attribute};
+
+#[rstest]
+#[case(alpine::x_data("{ open: false }"), "x-data=\"{ open: false }\"")]
+#[case(alpine::x_show("open"), "x-show=\"open\"")]
+#[case(alpine::x_if("open"), "x-if=\"open\"")]
+#[case(alpine::x_for("item in items"), "x-for=\"item in items\"")]
+#[case(alpine::x_model("email"), "x-model=\"email\"")]
+#[case(alpine::x_text("count"), "x-text=\"count\"")]
+#[case(alpine::x_html("content"), "x-html=\"content\"")]
+#[case(alpine::x_on("click", "open = true"), "x-on:click=\"open = true\"")]
+#[case(
+    alpine::x_on("click.prevent", "submit()"),
+    "x-on:click.prevent=\"submit()\""
+)]
+#[case(
+    alpine::x_bind("class", "{ 'active': open }"),
+    "x-bind:class=\"{ 'active': open }\""
+)]
+#[case(
+    alpine::x_on("keyup.enter", r#"alert("hello")"#),
+    "x-on:keyup.enter=\"alert(&quot;hello&quot;)\""
+)]
+fn should_render_alpine_attribute(#[case] attr: Attribute, #[case] expected: &str) {
+    assert_eq!(attr.to_string(), expected);
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "axum")]
+
+use axum_core::response::IntoResponse;
+use http_body_util::BodyExt;
+
+use fun_html::{
+    attr::id,
+    elt::{body, div, head, li, title, ul},
+    html, Document,
+};
+
+fn large_document() -> Document {
+    html(
+        [],
+        [
+            head([], [title([], "Report")]),
+            body(
+                [],
+                [ul(
+                    [],
+                    (0..5000).map(|i| li([], [format!("item <{i}>").into()])),
+                )],
+            ),
+        ],
+    )
+}
+
+async fn collect_body(response: axum_core::response::Response) -> String {
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn document_should_implement_into_response() {
+    let doc = large_document();
+    let expected = doc.to_string();
+    let response = doc.into_response();
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/html; charset=utf-8"
+    );
+    assert_eq!(collect_body(response).await, expected);
+}
+
+#[tokio::test]
+async fn streamed_response_should_have_the_same_body_as_buffered_rendering() {
+    let doc = large_document();
+    let expected = doc.to_string();
+    let response = doc.into_stream_response();
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/html; charset=utf-8"
+    );
+    assert_eq!(collect_body(response).await, expected);
+}
+
+#[tokio::test]
+async fn streamed_response_should_be_sent_in_several_frames() {
+    let mut body = large_document().into_stream_response().into_body();
+    let mut frames = 0;
+    while let Some(frame) = body.frame().await {
+        assert!(frame.unwrap().is_data());
+        frames += 1;
+    }
+    assert!(frames > 1);
+}
+
+#[tokio::test]
+async fn element_should_implement_into_response() {
+    let response = div([id("foo")], ["hello".into()]).into_response();
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/html; charset=utf-8"
+    );
+    assert_eq!(collect_body(response).await, "<div id=\"foo\">hello</div>");
+}
@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+
+use rstest::rstest;
+
+use fun_html::{attr, elt, escape};
+
+#[rstest]
+#[case("")]
+#[case("hello world")]
+#[case("'single' and \"double\" quotes")]
+#[case("héllo wörld 🦀")]
+fn text_without_special_characters_should_be_borrowed(#[case] value: &str) {
+    assert!(matches!(escape::text(value), Cow::Borrowed(v) if v == value));
+}
+
+#[rstest]
+#[case("")]
+#[case("hello world")]
+#[case("'single' quotes")]
+#[case("héllo wörld 🦀")]
+fn attribute_without_special_characters_should_be_borrowed(#[case] value: &str) {
+    assert!(matches!(escape::attribute(value), Cow::Borrowed(v) if v == value));
+}
+
+#[rstest]
+#[case("<>&\"", "&lt;&gt;&amp;\"")]
+#[case("é<ü>🦀&ß", "é&lt;ü&gt;🦀&amp;ß")]
+#[case("&amp;", "&amp;amp;")]
+fn should_escape_text(#[case] value: &'static str, #[case] expected: &str) {
+    assert_eq!(escape::text(value), expected);
+    assert_eq!(elt::text(value).to_string(), expected);
+}
+
+#[rstest]
+#[case("<>&\"", "&lt;&gt;&amp;&quot;")]
+#[case("é\"ü\"🦀", "é&quot;ü&quot;🦀")]
+fn should_escape_attribute(#[case] value: &'static str, #[case] expected: &str) {
+    assert_eq!(escape::attribute(value), expected);
+    assert_eq!(attr::id(value).to_string(), format!("id=\"{expected}\""));
+}
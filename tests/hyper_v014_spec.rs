@@ -0,0 +1,30 @@
+#![cfg(feature = "hyper_v014")]
+
+use hyper_v014::{body::to_bytes, header::CONTENT_TYPE, Body, Response, StatusCode};
+use rstest::rstest;
+
+use fun_html::{
+    attr::id,
+    elt::{body, div, head},
+    html,
+};
+
+#[rstest]
+#[case(
+    html([], [head([], []), body([], ["hello".into()])]).into(),
+    "<!DOCTYPE html>\n<html><head></head><body>hello</body></html>"
+)]
+#[case(div([id("foo")], ["hello".into()]).into(), "<div id=\"foo\">hello</div>")]
+#[tokio::test]
+async fn should_convert_into_html_response(
+    #[case] response: Response<Body>,
+    #[case] expected_body: &str,
+) {
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(CONTENT_TYPE).unwrap(),
+        "text/html; charset=utf-8"
+    );
+    let bytes = to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(bytes, expected_body);
+}
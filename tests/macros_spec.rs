@@ -0,0 +1,138 @@
+#![cfg(feature = "macros")]
+
+use rstest::rstest;
+
+use fun_html::{attr, attrs, elt, html, text, Attribute, Element};
+
+#[rstest]
+#[case(html! {}, elt::none())]
+#[case(html! { "a < b" }, elt::text("a < b"))]
+#[case(html! { br; }, elt::br([]))]
+#[case(html! { div {} }, elt::div([], []))]
+#[case(html! { p { "Hello" } }, elt::p([], [elt::text("Hello")]))]
+#[case(
+    html! { div(class = "card") { h1 { "Title" } p { ("<dynamic>".to_string()) } } },
+    elt::div([Attribute::new("class", "card")], [elt::h1([], [elt::text("Title")]), elt::p([], [elt::text("<dynamic>")])])
+)]
+#[case(
+    html! { input(type = "text", name = "q", required); },
+    elt::input([attr::type_text(), attr::name("q"), attr::required()])
+)]
+#[case(
+    html! { img(src = "a.png", width = 10_u32, "data-id" = "x\"y"); },
+    elt::img([attr::src("a.png"), attr::width_int(10), attr::data("id", "x\"y")])
+)]
+#[case(
+    html! { button((attr::class(["a", "b"])), "hx-post" = "/send", disabled,) { "Send" } },
+    elt::button([attr::class(["a", "b"]), Attribute::new("hx-post", "/send"), attr::disabled()], [elt::text("Send")])
+)]
+#[case(
+    html! { label(for = "x") { "Name" } input(id = "x"); },
+    elt::fragment([elt::label([attr::for_("x")], [elt::text("Name")]), elt::input([attr::id("x")])])
+)]
+#[case(
+    html! { ul { (elt::li([], [elt::text("a")])) li { "b" } (elt::none()) } },
+    elt::ul([], [elt::li([], [elt::text("a")]), elt::li([], [elt::text("b")])])
+)]
+#[case(html! { custom("flag") { (Some(elt::hr([]))) } }, Element::new("custom", [Attribute::new_flag("flag")], [elt::hr([])]))]
+fn macro_should_expand_to_the_same_as_functions(
+    #[case] actual: Element,
+    #[case] expected: Element,
+) {
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn macro_should_embed_expressions_built_at_runtime() {
+    let items = ["a", "<b>"];
+    let list = html! {
+        ul(id = "list") {
+            (items.iter().map(|item| html! { li { (*item) } }).collect::<Element>())
+        }
+    };
+    assert_eq!(
+        list.to_string(),
+        "<ul id=\"list\"><li>a</li><li>&lt;b&gt;</li></ul>"
+    );
+}
+
+#[test]
+fn single_root_node_should_not_be_wrapped_in_a_fragment() {
+    let element = html! { div {} }.with_attribute(attr::id("a"));
+    assert_eq!(element.to_string(), "<div id=\"a\"></div>");
+}
+
+#[rstest]
+#[case(attrs![], "<div></div>")]
+#[case(attrs![attr::id("x"), attr::class(["btn"])], "<div id=\"x\" class=\"btn\"></div>")]
+#[case(attrs![attr::id("x"), if true => attr::class(["a"])], "<div id=\"x\" class=\"a\"></div>")]
+#[case(attrs![attr::id("x"), if false => attr::class(["a"])], "<div id=\"x\"></div>")]
+#[case(attrs![if 1 > 2 => attr::id("x"), ("title", "t"),], "<div title=\"t\"></div>")]
+#[case(attrs![hidden ? true, attr::id("x")], "<div hidden id=\"x\"></div>")]
+#[case(attrs![hidden ? false, attr::id("x")], "<div id=\"x\"></div>")]
+fn attrs_macro_should_include_attributes_whose_guard_is_true(
+    #[case] attributes: Vec<Attribute>,
+    #[case] expected: &str,
+) {
+    assert_eq!(elt::div(attributes, []).to_string(), expected);
+}
+
+#[rstest]
+fn attrs_macro_should_work_inside_element_constructors(
+    #[values(true, false)] primary: bool,
+    #[values(true, false)] is_disabled: bool,
+) {
+    let element = elt::button(
+        attrs![
+            attr::id("x"),
+            attr::class(["btn"]),
+            if primary => attr::class(["btn-primary"]),
+            disabled ? is_disabled,
+        ],
+        ["Click".into()],
+    );
+    let expected = elt::button(
+        [
+            attr::id("x"),
+            attr::class(["btn"]),
+            if primary {
+                attr::class(["btn-primary"])
+            } else {
+                attr::none()
+            },
+            if is_disabled {
+                attr::disabled()
+            } else {
+                attr::none()
+            },
+        ],
+        ["Click".into()],
+    );
+    assert_eq!(element.to_string(), expected.to_string());
+}
+
+#[test]
+fn text_macro_should_format_positional_and_named_arguments() {
+    let name = "Jo";
+    assert_eq!(text!("static").to_string(), "static");
+    assert_eq!(text!("{} + {} = {}", 1, 2, 1 + 2).to_string(), "1 + 2 = 3");
+    assert_eq!(text!("{1}-{0}", "a", "b").to_string(), "b-a");
+    assert_eq!(text!("Hello, {name}!").to_string(), "Hello, Jo!");
+    assert_eq!(
+        text!("{greeting}, {name}", greeting = "Hi").to_string(),
+        "Hi, Jo"
+    );
+    assert_eq!(text!("{:>4}", 7).to_string(), "   7");
+}
+
+#[rstest]
+#[case(text!("{}", "<b>"), "&lt;b&gt;")]
+#[case(text!("a < {x}", x = "b & c"), "a &lt; b &amp; c")]
+#[case(text!("<i>"), "&lt;i&gt;")]
+fn text_macro_should_escape(#[case] element: Element, #[case] expected: &str) {
+    assert_eq!(element.to_string(), expected);
+    assert_eq!(
+        elt::p([], [element]).to_string(),
+        format!("<p>{expected}</p>")
+    );
+}
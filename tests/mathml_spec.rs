@@ -0,0 +1,70 @@
+use rstest::rstest;
+
+use fun_html::{
+    mathml::{self, display, math, mfrac, mi, mn, mo, mrow, msqrt, msub, msup, mtext, MathDisplay},
+    Element,
+};
+
+#[test]
+fn should_render_quadratic_formula() {
+    let formula = math(
+        [display(MathDisplay::Block)],
+        [mrow(
+            [],
+            [
+                mi([], ["x".into()]),
+                mo([], ["=".into()]),
+                mfrac(
+                    [],
+                    [
+                        mrow(
+                            [],
+                            [
+                                mo([], ["-".into()]),
+                                mi([], ["b".into()]),
+                                mo([], ["±".into()]),
+                                msqrt(
+                                    [],
+                                    [
+                                        msup([], [mi([], ["b".into()]), mn([], ["2".into()])]),
+                                        mo([], ["-".into()]),
+                                        mn([], ["4".into()]),
+                                        mi([], ["a".into()]),
+                                        mi([], ["c".into()]),
+                                    ],
+                                ),
+                            ],
+                        ),
+                        mrow([], [mn([], ["2".into()]), mi([], ["a".into()])]),
+                    ],
+                ),
+            ],
+        )],
+    );
+    assert_eq!(
+        formula.to_string(),
+        concat!(
+            r#"<math xmlns="http://www.w3.org/1998/Math/MathML" display="block">"#,
+            "<mrow><mi>x</mi><mo>=</mo><mfrac>",
+            "<mrow><mo>-</mo><mi>b</mi><mo>±</mo><msqrt><msup><mi>b</mi><mn>2</mn></msup><mo>-</mo><mn>4</mn><mi>a</mi><mi>c</mi></msqrt></mrow>",
+            "<mrow><mn>2</mn><mi>a</mi></mrow>",
+            "</mfrac></mrow></math>",
+        )
+    );
+}
+
+#[rstest]
+#[case(mo([], ["<".into()]), "<mo>&lt;</mo>")]
+#[case(mi([], ["a&b".into()]), "<mi>a&amp;b</mi>")]
+#[case(mn([], ["1>0".into()]), "<mn>1&gt;0</mn>")]
+#[case(mtext([], ["if x < 0".into()]), "<mtext>if x &lt; 0</mtext>")]
+#[case(msub([], [mi([], ["x".into()]), mn([], ["1".into()])]), "<msub><mi>x</mi><mn>1</mn></msub>")]
+#[case(math([display(MathDisplay::Inline)], []), "<math xmlns=\"http://www.w3.org/1998/Math/MathML\" display=\"inline\"></math>")]
+fn should_render_mathml_elements(#[case] element: Element, #[case] expected: &str) {
+    assert_eq!(element.to_string(), expected);
+}
+
+#[test]
+fn math_should_use_mathml_namespace() {
+    assert!(math([], []).to_string().contains(mathml::NAMESPACE));
+}
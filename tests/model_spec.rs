@@ -1,6 +1,6 @@
 use rstest::rstest;
 
-use fun_html::{Attribute, Element};
+use fun_html::{attr, elt, Attribute, Element};
 
 #[rstest]
 #[cfg(debug_assertions)]
@@ -11,6 +11,15 @@ fn should_panic_for_invalid_attribute_name(
     Attribute::new(name, "value");
 }
 
+#[rstest]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn should_panic_for_invalid_unsafe_attribute_name(
+    #[values("hello world", "hello\tworld", "hello\nworld", "")] name: &'static str,
+) {
+    Attribute::new_unsafe(name, "value");
+}
+
 #[rstest]
 #[cfg(debug_assertions)]
 #[should_panic]
@@ -20,6 +29,24 @@ fn should_panic_for_invalid_attribute_flag_name(
     Attribute::new_flag(name);
 }
 
+#[rstest]
+#[should_panic]
+fn should_panic_for_invalid_data_attribute_key(
+    #[values("", "fooBar", "foo bar", "foo\"bar", "foo>bar", "foo=bar", "foo:bar")]
+    key: &'static str,
+) {
+    attr::data(key, "value");
+}
+
+#[rstest]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn should_panic_for_invalid_aria_attribute_name(
+    #[values("hello world", "hello\tworld", "hello\nworld", "")] name: &'static str,
+) {
+    attr::aria(name, "value");
+}
+
 #[rstest]
 #[cfg(debug_assertions)]
 #[should_panic]
@@ -37,3 +64,35 @@ fn should_panic_for_invalid_void_element_name(
 ) {
     Element::new_void(name, []);
 }
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn should_panic_when_mixing_srcset_descriptors() {
+    attr::SrcSet::new()
+        .width("a.png", 320)
+        .density("b.png", 2.0);
+}
+
+#[rstest]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn should_panic_for_invalid_event_name(#[values("", "click me")] event: &'static str) {
+    attr::on(event, "f()");
+}
+
+#[rstest]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn should_panic_for_invalid_comment(#[values(">a", "->a", "a -- b", "a-")] text: &'static str) {
+    elt::comment(text);
+}
+
+#[rstest]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn should_panic_when_appending_children_to_void_element(
+    #[values(elt::br([]), elt::img([]), Element::new_void("custom", []))] element: Element,
+) {
+    let _ = element.with_child(elt::text("a"));
+}
@@ -1,9 +1,14 @@
+use std::borrow::Cow;
+
 use rstest::rstest;
 
 use fun_html::{
-    attr::{self, AnchorTarget},
-    elt::{self},
-    Attribute, Document, Element,
+    attr::{
+        self, AnchorTarget, AriaLive, Autocomplete, Dir, InputMode, Loading, ReferrerPolicy, Role,
+        Scope,
+    },
+    elt::{self, InvalidComment},
+    Attribute, Document, Element, RenderOptions,
 };
 
 #[test]
@@ -34,11 +39,33 @@ fn should_render_html_document() {
 #[case(("x-on:keyup.enter", "doSomething").into(), "x-on:keyup.enter=\"doSomething\"")]
 #[case(("@keyup.enter", "doSomething").into(), "@keyup.enter=\"doSomething\"")]
 #[case(Attribute::new_unsafe_name("hello".to_string(), "world".to_string()), "hello=\"world\"")]
+#[case(Attribute::new_value("tabindex", 3), "tabindex=\"3\"")]
+#[case(Attribute::new_value("tabindex", -3i32), "tabindex=\"-3\"")]
+#[case(Attribute::new_value("tabindex", 3u32), "tabindex=\"3\"")]
+#[case(
+    Attribute::new_value("tabindex", u64::MAX),
+    "tabindex=\"18446744073709551615\""
+)]
+#[case(Attribute::new_value("step", 0.5), "step=\"0.5\"")]
+#[case(Attribute::new_value("step", 2.0), "step=\"2\"")]
+#[case(Attribute::new_value("step", 1e21), "step=\"1000000000000000000000\"")]
+#[case(Attribute::new_value("step", 1e-7), "step=\"0.0000001\"")]
+#[case(Attribute::new_value("draggable", true), "draggable=\"true\"")]
+#[case(Attribute::new_value("draggable", false), "draggable=\"false\"")]
+#[case(Attribute::new_value("accesskey", 's'), "accesskey=\"s\"")]
+#[case(Attribute::new_value("accesskey", '"'), "accesskey=\"&quot;\"")]
 #[case(attr::id("foo"), "id=\"foo\"")]
 #[case(attr::class(["foo"]), "class=\"foo\"")]
 #[case(attr::class(["foo", "bar"]), "class=\"foo bar\"")]
+#[case(attr::classes(["btn", "", "active", "btn"]), "class=\"btn active\"")]
+#[case(attr::classes([String::from("a"), String::from(" "), String::from("b")]), "class=\"a b\"")]
+#[case(attr::classes(Vec::<&str>::new()), "class=\"\"")]
+#[case(attr::classes([Cow::Borrowed("a"), Cow::Borrowed(" a"), Cow::Owned("b ".into()), Cow::Borrowed(" b  ")]), "class=\"a b\"")]
+#[case(attr::classes([Cow::Borrowed("x"), Cow::Owned("x".into()), Cow::Borrowed("<y>")]), "class=\"x &lt;y&gt;\"")]
 #[case(attr::href("foo"), "href=\"foo\"")]
+#[case(attr::hreflang("fr"), "hreflang=\"fr\"")]
 #[case(attr::rel("foo"), "rel=\"foo\"")]
+#[case(attr::as_("font"), "as=\"font\"")]
 #[case(attr::src("foo"), "src=\"foo\"")]
 #[case(attr::type_("foo"), "type=\"foo\"")]
 #[case(attr::type_text(), "type=\"text\"")]
@@ -56,8 +83,10 @@ fn should_render_html_document() {
 #[case(attr::type_reset(), "type=\"reset\"")]
 #[case(attr::type_submit(), "type=\"submit\"")]
 #[case(attr::integrity("foo"), "integrity=\"foo\"")]
+#[case(attr::nonce("r4nd0m"), "nonce=\"r4nd0m\"")]
 #[case(attr::defer(), "defer")]
 #[case(attr::async_(), "async")]
+#[case(attr::crossorigin(""), "crossorigin=\"\"")]
 #[case(attr::crossorigin_anonymous(), "crossorigin=\"anonymous\"")]
 #[case(attr::crossorigin_use_credentials(), "crossorigin=\"use-credentials\"")]
 #[case(attr::download(), "download")]
@@ -74,6 +103,7 @@ fn should_render_html_document() {
 #[case(attr::charset_utf8(), "charset=\"UTF-8\"")]
 #[case(attr::name("hello"), "name=\"hello\"")]
 #[case(attr::content("bla"), "content=\"bla\"")]
+#[case(attr::property("og:title"), "property=\"og:title\"")]
 #[case(attr::alt("bla"), "alt=\"bla\"")]
 #[case(attr::width("10"), "width=\"10\"")]
 #[case(attr::height("10"), "height=\"10\"")]
@@ -85,24 +115,362 @@ fn should_render_html_document() {
 #[case(attr::for_("foo"), "for=\"foo\"")]
 #[case(attr::value("hello"), "value=\"hello\"")]
 #[case(attr::required(), "required")]
+#[case(attr::selected(), "selected")]
+#[case(attr::checked(), "checked")]
 #[case(attr::disabled(), "disabled")]
+#[case(attr::hidden(), "hidden")]
 #[case(attr::autofocus(), "autofocus")]
 #[case(attr::autocomplete("email"), "autocomplete=\"email\"")]
+#[case(attr::autocomplete(Autocomplete::Off), "autocomplete=\"off\"")]
+#[case(attr::autocomplete(Autocomplete::On), "autocomplete=\"on\"")]
+#[case(
+    attr::autocomplete(Autocomplete::Shipping),
+    "autocomplete=\"shipping\""
+)]
+#[case(attr::autocomplete(Autocomplete::Billing), "autocomplete=\"billing\"")]
+#[case(attr::autocomplete(Autocomplete::Home), "autocomplete=\"home\"")]
+#[case(attr::autocomplete(Autocomplete::Work), "autocomplete=\"work\"")]
+#[case(attr::autocomplete(Autocomplete::Mobile), "autocomplete=\"mobile\"")]
+#[case(attr::autocomplete(Autocomplete::Name), "autocomplete=\"name\"")]
+#[case(
+    attr::autocomplete(Autocomplete::HonorificPrefix),
+    "autocomplete=\"honorific-prefix\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::GivenName),
+    "autocomplete=\"given-name\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::AdditionalName),
+    "autocomplete=\"additional-name\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::FamilyName),
+    "autocomplete=\"family-name\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::HonorificSuffix),
+    "autocomplete=\"honorific-suffix\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::Nickname),
+    "autocomplete=\"nickname\""
+)]
+#[case(attr::autocomplete(Autocomplete::Email), "autocomplete=\"email\"")]
+#[case(
+    attr::autocomplete(Autocomplete::Username),
+    "autocomplete=\"username\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::NewPassword),
+    "autocomplete=\"new-password\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::CurrentPassword),
+    "autocomplete=\"current-password\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::OneTimeCode),
+    "autocomplete=\"one-time-code\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::OrganizationTitle),
+    "autocomplete=\"organization-title\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::Organization),
+    "autocomplete=\"organization\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::StreetAddress),
+    "autocomplete=\"street-address\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::AddressLine1),
+    "autocomplete=\"address-line1\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::AddressLine2),
+    "autocomplete=\"address-line2\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::AddressLine3),
+    "autocomplete=\"address-line3\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::AddressLevel1),
+    "autocomplete=\"address-level1\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::AddressLevel2),
+    "autocomplete=\"address-level2\""
+)]
+#[case(attr::autocomplete(Autocomplete::Country), "autocomplete=\"country\"")]
+#[case(
+    attr::autocomplete(Autocomplete::CountryName),
+    "autocomplete=\"country-name\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::PostalCode),
+    "autocomplete=\"postal-code\""
+)]
+#[case(attr::autocomplete(Autocomplete::CcName), "autocomplete=\"cc-name\"")]
+#[case(
+    attr::autocomplete(Autocomplete::CcNumber),
+    "autocomplete=\"cc-number\""
+)]
+#[case(attr::autocomplete(Autocomplete::CcExp), "autocomplete=\"cc-exp\"")]
+#[case(
+    attr::autocomplete(Autocomplete::CcExpMonth),
+    "autocomplete=\"cc-exp-month\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::CcExpYear),
+    "autocomplete=\"cc-exp-year\""
+)]
+#[case(attr::autocomplete(Autocomplete::CcCsc), "autocomplete=\"cc-csc\"")]
+#[case(attr::autocomplete(Autocomplete::CcType), "autocomplete=\"cc-type\"")]
+#[case(
+    attr::autocomplete(Autocomplete::TransactionCurrency),
+    "autocomplete=\"transaction-currency\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::TransactionAmount),
+    "autocomplete=\"transaction-amount\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::Language),
+    "autocomplete=\"language\""
+)]
+#[case(attr::autocomplete(Autocomplete::Bday), "autocomplete=\"bday\"")]
+#[case(attr::autocomplete(Autocomplete::Sex), "autocomplete=\"sex\"")]
+#[case(attr::autocomplete(Autocomplete::Tel), "autocomplete=\"tel\"")]
+#[case(attr::autocomplete(Autocomplete::Url), "autocomplete=\"url\"")]
+#[case(attr::autocomplete(Autocomplete::Photo), "autocomplete=\"photo\"")]
+#[case(attr::autocomplete(Autocomplete::Section("blue".into())), "autocomplete=\"section-blue\"")]
+#[case(attr::autocomplete(Autocomplete::Custom("webauthn".into())), "autocomplete=\"webauthn\"")]
+#[case(attr::autocomplete_tokens([]), "autocomplete=\"\"")]
+#[case(attr::autocomplete_tokens([Autocomplete::Section("blue".into()), Autocomplete::Shipping, Autocomplete::StreetAddress]), "autocomplete=\"section-blue shipping street-address\"")]
+#[case(attr::autocomplete_tokens([Autocomplete::Work, Autocomplete::Email]), "autocomplete=\"work email\"")]
 #[case(attr::autocomplete_on(), "autocomplete=\"on\"")]
 #[case(attr::autocomplete_off(), "autocomplete=\"off\"")]
 #[case(attr::pattern("foobar"), "pattern=\"foobar\"")]
 #[case(attr::min("value"), "min=\"value\"")]
 #[case(attr::max("value"), "max=\"value\"")]
+#[case(attr::min("2024-01-01"), "min=\"2024-01-01\"")]
+#[case(attr::step("0.01"), "step=\"0.01\"")]
+#[case(attr::step("any"), "step=\"any\"")]
 #[case(attr::minlength("value"), "minlength=\"value\"")]
 #[case(attr::maxlength("value"), "maxlength=\"value\"")]
 #[case(attr::minlength_u16(42), "minlength=\"42\"")]
 #[case(attr::maxlength_u16(42), "maxlength=\"42\"")]
+#[case(attr::minlength_usize(8), "minlength=\"8\"")]
+#[case(attr::maxlength_usize(255), "maxlength=\"255\"")]
+#[case(attr::inputmode(InputMode::None), "inputmode=\"none\"")]
+#[case(attr::inputmode(InputMode::Text), "inputmode=\"text\"")]
+#[case(attr::inputmode(InputMode::Decimal), "inputmode=\"decimal\"")]
+#[case(attr::inputmode(InputMode::Numeric), "inputmode=\"numeric\"")]
+#[case(attr::inputmode(InputMode::Tel), "inputmode=\"tel\"")]
+#[case(attr::inputmode(InputMode::Search), "inputmode=\"search\"")]
+#[case(attr::inputmode(InputMode::Email), "inputmode=\"email\"")]
+#[case(attr::inputmode(InputMode::Url), "inputmode=\"url\"")]
 #[case(attr::multiple(), "multiple")]
 #[case(attr::placeholder("hello"), "placeholder=\"hello\"")]
 #[case(attr::rows(10), "rows=\"10\"")]
 #[case(attr::cols(10), "cols=\"10\"")]
+#[case(attr::rows_u32(4), "rows=\"4\"")]
+#[case(attr::cols_u32(40), "cols=\"40\"")]
+#[case(attr::span_attr(2), "span=\"2\"")]
+#[case(attr::colspan(3), "colspan=\"3\"")]
+#[case(attr::rowspan(2), "rowspan=\"2\"")]
+#[case(attr::scope(Scope::Col), "scope=\"col\"")]
+#[case(attr::scope(Scope::Row), "scope=\"row\"")]
+#[case(attr::scope(Scope::Colgroup), "scope=\"colgroup\"")]
+#[case(attr::scope(Scope::Rowgroup), "scope=\"rowgroup\"")]
+#[case(attr::scope("auto"), "scope=\"auto\"")]
+#[case(attr::dir(Dir::Ltr), "dir=\"ltr\"")]
+#[case(attr::dir(Dir::Rtl), "dir=\"rtl\"")]
+#[case(attr::dir(Dir::Auto), "dir=\"auto\"")]
 #[case(attr::style("width: 5px"), "style=\"width: 5px\"")]
+#[case(attr::media("print"), "media=\"print\"")]
+#[case(attr::media_min_width(800), "media=\"(min-width: 800px)\"")]
+#[case(attr::media_max_width(600), "media=\"(max-width: 600px)\"")]
+#[case(attr::media_prefers_dark(), "media=\"(prefers-color-scheme: dark)\"")]
 #[case(attr::role_str("menu"), "role=\"menu\"")]
+#[case(attr::data("foo", "bar"), "data-foo=\"bar\"")]
+#[case(attr::data("user-id_2".to_string(), "<42>"), "data-user-id_2=\"&lt;42&gt;\"")]
+#[case(attr::itemscope(), "itemscope")]
+#[case(attr::itemprop("name"), "itemprop=\"name\"")]
+#[case(
+    attr::itemtype("https://schema.org/Product"),
+    "itemtype=\"https://schema.org/Product\""
+)]
+#[case(
+    attr::itemtype("https://example.com/?a=1&b=\"2\""),
+    "itemtype=\"https://example.com/?a=1&amp;b=&quot;2&quot;\""
+)]
+#[case(
+    attr::itemid("urn:isbn:0-330-34032-8"),
+    "itemid=\"urn:isbn:0-330-34032-8\""
+)]
+#[case(attr::aria("colcount", 12), "aria-colcount=\"12\"")]
+#[case(attr::aria("sort", "ascending"), "aria-sort=\"ascending\"")]
+#[case(
+    attr::aria_label("Close <dialog>"),
+    "aria-label=\"Close &lt;dialog&gt;\""
+)]
+#[case(attr::aria_labelledby("a b"), "aria-labelledby=\"a b\"")]
+#[case(attr::aria_describedby("hint"), "aria-describedby=\"hint\"")]
+#[case(attr::aria_controls("menu"), "aria-controls=\"menu\"")]
+#[case(attr::aria_current("page"), "aria-current=\"page\"")]
+#[case(attr::aria_haspopup("menu"), "aria-haspopup=\"menu\"")]
+#[case(attr::aria_live(AriaLive::Off), "aria-live=\"off\"")]
+#[case(attr::aria_live(AriaLive::Polite), "aria-live=\"polite\"")]
+#[case(attr::aria_live(AriaLive::Assertive), "aria-live=\"assertive\"")]
+#[case(attr::aria_atomic(true), "aria-atomic=\"true\"")]
+#[case(attr::aria_busy(false), "aria-busy=\"false\"")]
+#[case(attr::aria_hidden(true), "aria-hidden=\"true\"")]
+#[case(attr::aria_expanded(false), "aria-expanded=\"false\"")]
+#[case(attr::aria_selected(true), "aria-selected=\"true\"")]
+#[case(attr::aria_checked(true), "aria-checked=\"true\"")]
+#[case(attr::aria_pressed(false), "aria-pressed=\"false\"")]
+#[case(attr::aria_disabled(true), "aria-disabled=\"true\"")]
+#[case(attr::aria_invalid(true), "aria-invalid=\"true\"")]
+#[case(attr::aria_modal(true), "aria-modal=\"true\"")]
+#[case(attr::aria_required(true), "aria-required=\"true\"")]
+#[case(attr::aria_level(2), "aria-level=\"2\"")]
+#[case(attr::aria_valuenow(42.5), "aria-valuenow=\"42.5\"")]
+#[case(attr::aria_valuemin(0.0), "aria-valuemin=\"0\"")]
+#[case(attr::aria_valuemax(100.0), "aria-valuemax=\"100\"")]
+#[case(attr::srcset("a.png 1x, b.png 2x"), "srcset=\"a.png 1x, b.png 2x\"")]
+#[case(attr::sizes("32x32"), "sizes=\"32x32\"")]
+#[case(attr::onclick("f()"), "onclick=\"f()\"")]
+#[case(attr::ondblclick("f()"), "ondblclick=\"f()\"")]
+#[case(attr::onsubmit("f()"), "onsubmit=\"f()\"")]
+#[case(attr::onreset("f()"), "onreset=\"f()\"")]
+#[case(attr::oninput("f()"), "oninput=\"f()\"")]
+#[case(attr::onchange("f()"), "onchange=\"f()\"")]
+#[case(attr::onkeydown("f()"), "onkeydown=\"f()\"")]
+#[case(attr::onkeyup("f()"), "onkeyup=\"f()\"")]
+#[case(attr::onfocus("f()"), "onfocus=\"f()\"")]
+#[case(attr::onblur("f()"), "onblur=\"f()\"")]
+#[case(attr::onmouseover("f()"), "onmouseover=\"f()\"")]
+#[case(attr::onmouseout("f()"), "onmouseout=\"f()\"")]
+#[case(attr::onload("f()"), "onload=\"f()\"")]
+#[case(attr::onerror("f()"), "onerror=\"f()\"")]
+#[case(attr::on("pointerdown", "f()"), "onpointerdown=\"f()\"")]
+#[case(attr::onclick("say(\"hi\")"), "onclick=\"say(&quot;hi&quot;)\"")]
+#[case(
+    attr::sizes("(max-width: 600px) 480px, 800px"),
+    "sizes=\"(max-width: 600px) 480px, 800px\""
+)]
+#[case(attr::SrcSet::new().into(), "srcset=\"\"")]
+#[case(attr::SrcSet::new().width("img-320.png", 320).width("img-640.png", 640).into(), "srcset=\"img-320.png 320w, img-640.png 640w\"")]
+#[case(attr::SrcSet::new().density("img.png", 1.0).density("img@2x.png", 2.0).density("img@1.5x.png", 1.5).into(), "srcset=\"img.png 1x, img@2x.png 2x, img@1.5x.png 1.5x\"")]
+#[case(attr::role("doc-toc"), "role=\"doc-toc\"")]
+#[case(attr::role(Role::Alert), "role=\"alert\"")]
+#[case(attr::role(Role::AlertDialog), "role=\"alertdialog\"")]
+#[case(attr::role(Role::Article), "role=\"article\"")]
+#[case(attr::role(Role::Banner), "role=\"banner\"")]
+#[case(attr::role(Role::Button), "role=\"button\"")]
+#[case(attr::role(Role::Cell), "role=\"cell\"")]
+#[case(attr::role(Role::Checkbox), "role=\"checkbox\"")]
+#[case(attr::role(Role::ColumnHeader), "role=\"columnheader\"")]
+#[case(attr::role(Role::Combobox), "role=\"combobox\"")]
+#[case(attr::role(Role::Complementary), "role=\"complementary\"")]
+#[case(attr::role(Role::ContentInfo), "role=\"contentinfo\"")]
+#[case(attr::role(Role::Dialog), "role=\"dialog\"")]
+#[case(attr::role(Role::Document), "role=\"document\"")]
+#[case(attr::role(Role::Feed), "role=\"feed\"")]
+#[case(attr::role(Role::Figure), "role=\"figure\"")]
+#[case(attr::role(Role::Form), "role=\"form\"")]
+#[case(attr::role(Role::Grid), "role=\"grid\"")]
+#[case(attr::role(Role::GridCell), "role=\"gridcell\"")]
+#[case(attr::role(Role::Group), "role=\"group\"")]
+#[case(attr::role(Role::Heading), "role=\"heading\"")]
+#[case(attr::role(Role::Img), "role=\"img\"")]
+#[case(attr::role(Role::Link), "role=\"link\"")]
+#[case(attr::role(Role::List), "role=\"list\"")]
+#[case(attr::role(Role::ListBox), "role=\"listbox\"")]
+#[case(attr::role(Role::ListItem), "role=\"listitem\"")]
+#[case(attr::role(Role::Log), "role=\"log\"")]
+#[case(attr::role(Role::Main), "role=\"main\"")]
+#[case(attr::role(Role::Marquee), "role=\"marquee\"")]
+#[case(attr::role(Role::Math), "role=\"math\"")]
+#[case(attr::role(Role::Menu), "role=\"menu\"")]
+#[case(attr::role(Role::MenuBar), "role=\"menubar\"")]
+#[case(attr::role(Role::MenuItem), "role=\"menuitem\"")]
+#[case(attr::role(Role::MenuItemCheckbox), "role=\"menuitemcheckbox\"")]
+#[case(attr::role(Role::MenuItemRadio), "role=\"menuitemradio\"")]
+#[case(attr::role(Role::Meter), "role=\"meter\"")]
+#[case(attr::role(Role::Navigation), "role=\"navigation\"")]
+#[case(attr::role(Role::None), "role=\"none\"")]
+#[case(attr::role(Role::Note), "role=\"note\"")]
+#[case(attr::role(Role::Option), "role=\"option\"")]
+#[case(attr::role(Role::Presentation), "role=\"presentation\"")]
+#[case(attr::role(Role::ProgressBar), "role=\"progressbar\"")]
+#[case(attr::role(Role::Radio), "role=\"radio\"")]
+#[case(attr::role(Role::RadioGroup), "role=\"radiogroup\"")]
+#[case(attr::role(Role::Region), "role=\"region\"")]
+#[case(attr::role(Role::Row), "role=\"row\"")]
+#[case(attr::role(Role::RowGroup), "role=\"rowgroup\"")]
+#[case(attr::role(Role::RowHeader), "role=\"rowheader\"")]
+#[case(attr::role(Role::ScrollBar), "role=\"scrollbar\"")]
+#[case(attr::role(Role::Search), "role=\"search\"")]
+#[case(attr::role(Role::SearchBox), "role=\"searchbox\"")]
+#[case(attr::role(Role::Separator), "role=\"separator\"")]
+#[case(attr::role(Role::Slider), "role=\"slider\"")]
+#[case(attr::role(Role::SpinButton), "role=\"spinbutton\"")]
+#[case(attr::role(Role::Status), "role=\"status\"")]
+#[case(attr::role(Role::Switch), "role=\"switch\"")]
+#[case(attr::role(Role::Tab), "role=\"tab\"")]
+#[case(attr::role(Role::Table), "role=\"table\"")]
+#[case(attr::role(Role::TabList), "role=\"tablist\"")]
+#[case(attr::role(Role::TabPanel), "role=\"tabpanel\"")]
+#[case(attr::role(Role::Term), "role=\"term\"")]
+#[case(attr::role(Role::TextBox), "role=\"textbox\"")]
+#[case(attr::role(Role::Timer), "role=\"timer\"")]
+#[case(attr::role(Role::Toolbar), "role=\"toolbar\"")]
+#[case(attr::role(Role::Tooltip), "role=\"tooltip\"")]
+#[case(attr::role(Role::Tree), "role=\"tree\"")]
+#[case(attr::role(Role::TreeGrid), "role=\"treegrid\"")]
+#[case(attr::role(Role::TreeItem), "role=\"treeitem\"")]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::NoReferrer),
+    "referrerpolicy=\"no-referrer\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::NoReferrerWhenDowngrade),
+    "referrerpolicy=\"no-referrer-when-downgrade\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::Origin),
+    "referrerpolicy=\"origin\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::OriginWhenCrossOrigin),
+    "referrerpolicy=\"origin-when-cross-origin\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::SameOrigin),
+    "referrerpolicy=\"same-origin\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::StrictOrigin),
+    "referrerpolicy=\"strict-origin\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::StrictOriginWhenCrossOrigin),
+    "referrerpolicy=\"strict-origin-when-cross-origin\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::UnsafeUrl),
+    "referrerpolicy=\"unsafe-url\""
+)]
+#[case(attr::loading(Loading::Lazy), "loading=\"lazy\"")]
+#[case(attr::loading(Loading::Eager), "loading=\"eager\"")]
+#[case(attr::loading(Loading::Auto), "loading=\"auto\"")]
+#[case(attr::loading("lazy"), "loading=\"lazy\"")]
 fn should_render_attribute(#[case] attr: Attribute, #[case] expected: &str) {
     assert_eq!(attr.to_string(), expected);
 }
@@ -127,8 +495,44 @@ fn should_render_attribute(#[case] attr: Attribute, #[case] expected: &str) {
     elt::link_stylesheet("/styles.css"),
     "<link rel=\"stylesheet\" href=\"/styles.css\">"
 )]
+#[case(
+    elt::link_canonical("https://example.com/a?b=1&c=2"),
+    "<link rel=\"canonical\" href=\"https://example.com/a?b=1&amp;c=2\">"
+)]
+#[case(
+    elt::link_icon("/favicon.ico"),
+    "<link rel=\"icon\" href=\"/favicon.ico\">"
+)]
+#[case(
+    elt::link_icon_with_type("/icon.svg", "image/svg+xml", "any"),
+    "<link rel=\"icon\" href=\"/icon.svg\" type=\"image/svg+xml\" sizes=\"any\">"
+)]
+#[case(
+    elt::link_preload("/app.js?v=1&x=2", "script"),
+    "<link rel=\"preload\" href=\"/app.js?v=1&amp;x=2\" as=\"script\">"
+)]
+#[case(
+    elt::link_prefetch("/next.html"),
+    "<link rel=\"prefetch\" href=\"/next.html\">"
+)]
 #[case(elt::script([("foo", "bar").into()], "alert('hello');"), "<script foo=\"bar\">alert('hello');</script>")]
 #[case(elt::script_empty([attr::src("/foo.js")]), "<script src=\"/foo.js\"></script>")]
+#[case(
+    elt::script_inline("let a = 1 < 2;"),
+    "<script>let a = 1 < 2;</script>"
+)]
+#[case(
+    elt::script_inline(String::from("x = '</SCRIPT>'")),
+    "<script>x = '<\\/SCRIPT>'</script>"
+)]
+#[case(
+    elt::script_inline("s = '<!-- <script>'"),
+    "<script>s = '<\\!-- <script>'</script>"
+)]
+#[case(
+    elt::script_inline_with([attr::type_("module"), attr::nonce("abc")], "import './a.js';"),
+    "<script type=\"module\" nonce=\"abc\">import './a.js';</script>"
+)]
 #[case(elt::meta([("foo", "bar").into()]), "<meta foo=\"bar\">")]
 #[allow(deprecated)]
 #[case(elt::meta_charset_utf_8(), "<meta charset=\"UTF-8\">")]
@@ -141,8 +545,53 @@ fn should_render_attribute(#[case] attr: Attribute, #[case] expected: &str) {
     elt::meta_color_scheme("dark"),
     "<meta name=\"color-scheme\" content=\"dark\">"
 )]
+#[case(
+    elt::meta_description("Fast & \"simple\" HTML"),
+    "<meta name=\"description\" content=\"Fast &amp; &quot;simple&quot; HTML\">"
+)]
+#[case(
+    fun_html::conv::meta_description("A site"),
+    "<meta name=\"description\" content=\"A site\">"
+)]
+#[case(
+    elt::meta_og_title("Title"),
+    "<meta property=\"og:title\" content=\"Title\">"
+)]
+#[case(
+    elt::meta_og_description("A & B"),
+    "<meta property=\"og:description\" content=\"A &amp; B\">"
+)]
+#[case(
+    elt::meta_og_image("https://example.com/a.png"),
+    "<meta property=\"og:image\" content=\"https://example.com/a.png\">"
+)]
+#[case(
+    elt::meta_og_type("article"),
+    "<meta property=\"og:type\" content=\"article\">"
+)]
+#[case(
+    elt::meta_og_url("https://example.com/"),
+    "<meta property=\"og:url\" content=\"https://example.com/\">"
+)]
+#[case(
+    elt::meta_twitter_card("summary_large_image"),
+    "<meta name=\"twitter:card\" content=\"summary_large_image\">"
+)]
+#[case(
+    elt::meta_twitter_title("Title"),
+    "<meta name=\"twitter:title\" content=\"Title\">"
+)]
+#[case(
+    elt::meta_twitter_description("A < B"),
+    "<meta name=\"twitter:description\" content=\"A &lt; B\">"
+)]
+#[case(
+    elt::meta_twitter_image("https://example.com/a.png"),
+    "<meta name=\"twitter:image\" content=\"https://example.com/a.png\">"
+)]
 #[case(elt::div([("foo", "bar").into()], ["hello".into()]), "<div foo=\"bar\">hello</div>")]
 #[case(elt::div([("foo", "bar".to_string()).into()], [elt::text("hello".to_string())]), "<div foo=\"bar\">hello</div>")]
+#[case(elt::html_element([attr::lang("en")], [elt::head([], []), elt::body([], [])]), "<html lang=\"en\"><head></head><body></body></html>")]
 #[case(elt::head([attr::id("foo")], [elt::text("hello")]), "<head id=\"foo\">hello</head>")]
 #[case(elt::legend([attr::id("foo")], [elt::text("hello")]), "<legend id=\"foo\">hello</legend>")]
 #[case(elt::title([("foo", "bar").into()], "hello"), "<title foo=\"bar\">hello</title>")]
@@ -188,6 +637,22 @@ fn should_render_attribute(#[case] attr: Attribute, #[case] expected: &str) {
 #[case(elt::button([("foo", "bar").into()], ["hello".into()]), "<button foo=\"bar\">hello</button>")]
 #[case(elt::label([attr::for_("foo")], ["hello".into()]), "<label for=\"foo\">hello</label>")]
 #[case(elt::fieldset([("foo", "bar").into()], ["hello".into()]), "<fieldset foo=\"bar\">hello</fieldset>")]
+#[case(
+    elt::labeled_input("name", "Name <required>", [attr::required()]),
+    r#"<label for="name">Name &lt;required&gt;</label><input id="name" required>"#
+)]
+#[case(
+    elt::link([attr::rel("stylesheet"), attr::href("/print.css"), attr::media("print")]),
+    r#"<link rel="stylesheet" href="/print.css" media="print">"#
+)]
+#[case(
+    elt::source([attr::srcset("/dark.png"), attr::media_prefers_dark()]),
+    r#"<source srcset="/dark.png" media="(prefers-color-scheme: dark)">"#
+)]
+#[case(elt::input([Attribute::new_option("value", None::<&str>)]), "<input>")]
+#[case(elt::input([attr::name("a"), Attribute::new_option("value", None::<String>), attr::required()]), "<input name=\"a\" required>")]
+#[case(elt::input([Attribute::new_option("value", Some(""))]), "<input value=\"\">")]
+#[case(elt::input([Attribute::new_option("value", Some(42))]), "<input value=\"42\">")]
 fn should_render_element(#[case] def: Element, #[case] expected: &str) {
     assert_eq!(def.to_string(), expected);
 }
@@ -212,8 +677,1654 @@ fn attribute_should_be_escaped() {
     );
 }
 
+#[test]
+fn unsafe_attribute_value_should_not_be_escaped() {
+    let string = elt::div(
+        [
+            Attribute::new("title", "&copy; <me>"),
+            Attribute::new_unsafe("data-raw", "&copy; <me>"),
+        ],
+        [],
+    )
+    .to_string();
+    assert_eq!(
+        string,
+        "<div title=\"&amp;copy; &lt;me&gt;\" data-raw=\"&copy; <me>\"></div>"
+    );
+}
+
+#[test]
+fn repeated_attributes_should_be_merged_on_elements_with_many_attributes() {
+    let mut attributes: Vec<Attribute> = (0..20)
+        .map(|i| attr::data(i.to_string(), i.to_string()))
+        .collect();
+    attributes.insert(3, attr::class(["a"]));
+    attributes.push(attr::class(["b"]));
+    attributes.push(attr::id("x"));
+    attributes.push(attr::id("y"));
+    let html = elt::div(attributes, []).to_string();
+    assert!(
+        html.starts_with("<div data-0=\"0\" data-1=\"1\" data-2=\"2\" class=\"a b\" data-3=\"3\""),
+        "{html}"
+    );
+    assert!(html.ends_with("data-19=\"19\" id=\"y\"></div>"), "{html}");
+}
+
+#[test]
+fn length_attributes_should_accept_generic_cow_values() {
+    fn min<T: Into<Cow<'static, str>>>(value: T) -> Attribute {
+        attr::minlength(value)
+    }
+    fn max<T: Into<Cow<'static, str>>>(value: T) -> Attribute {
+        attr::maxlength(value)
+    }
+    assert_eq!(min("2").to_string(), "minlength=\"2\"");
+    assert_eq!(max(String::from("8")).to_string(), "maxlength=\"8\"");
+}
+
+#[test]
+fn textarea_size_attributes_should_infer_i32_values() {
+    let n: u8 = 4;
+    assert_eq!(attr::cols(n.into()).to_string(), "cols=\"4\"");
+    assert_eq!(attr::rows(n as _).to_string(), "rows=\"4\"");
+}
+
+#[test]
+fn attribute_new_should_accept_generic_cow_values() {
+    fn data_name<T: Into<Cow<'static, str>>>(value: T) -> Attribute {
+        Attribute::new("data-name", value)
+    }
+    fn pair<T: Into<Cow<'static, str>>>(value: T) -> Attribute {
+        ("data-name", value).into()
+    }
+    assert_eq!(data_name("a").to_string(), "data-name=\"a\"");
+    assert_eq!(pair(String::from("b")).to_string(), "data-name=\"b\"");
+}
+
 #[rstest]
 fn script_should_be_escaped() {
     let string = elt::script([], "alert('</script>');").to_string();
     assert_eq!(string, "<script>alert('<\\/script>');</script>");
 }
+
+#[rstest]
+#[case("a('</script>')", "<script>a('<\\/script>')</script>")]
+#[case("a('</script x')", "<script>a('<\\/script x')</script>")]
+#[case("a('</script\t>')", "<script>a('<\\/script\t>')</script>")]
+#[case("a('</script\n')", "<script>a('<\\/script\n')</script>")]
+#[case("a('</script/>')", "<script>a('<\\/script/>')</script>")]
+#[case("a('</scripts')", "<script>a('<\\/scripts')</script>")]
+#[case("a('</SCRIPT')", "<script>a('<\\/SCRIPT')</script>")]
+#[case("a('<</script>')", "<script>a('<<\\/script>')</script>")]
+fn script_should_escape_every_end_tag_prefix(
+    #[case] content: &'static str,
+    #[case] expected: &str,
+) {
+    assert_eq!(elt::script([], content).to_string(), expected);
+}
+
+#[test]
+fn script_should_not_escape_comment_start() {
+    let string = elt::script([], "if (a <!--b) {}").to_string();
+    assert_eq!(string, "<script>if (a <!--b) {}</script>");
+}
+
+#[rstest]
+#[case("</script>")]
+#[case("</ScRiPt >")]
+#[case("<!-- <script> -->")]
+#[case("a <!--b--> </script><script>alert(1)</script>")]
+fn script_inline_string_literal_should_keep_its_value(
+    #[case] value: &'static str,
+    #[values('"', '\'', '`')] quote: char,
+) {
+    let js = format!("const s = {quote}{value}{quote};");
+    let html = fun_html::html(
+        [],
+        [elt::head([], [elt::script_inline(js)]), elt::body([], [])],
+    )
+    .to_string();
+    let script = script_data(&html);
+    assert!(!script.contains("<!--"), "{html}");
+    let literal = script
+        .strip_prefix("const s = ")
+        .and_then(|s| s.strip_suffix(';'))
+        .unwrap();
+    assert_eq!(js_string_literal_value(literal, quote), value);
+}
+
+/// Content of the first `<script>` element, ending where the HTML tokenizer ends the script data state
+fn script_data(html: &str) -> &str {
+    let lowercase = html.to_ascii_lowercase();
+    let start = lowercase.find("<script>").unwrap() + "<script>".len();
+    let end = start + lowercase[start..].find("</script").unwrap();
+    assert_eq!(html.matches("</script>").count(), 1, "{html}");
+    &html[start..end]
+}
+
+/// Value of a JavaScript string (or template) literal, following the escape rules of the ECMAScript spec
+/// (a backslash followed by a character without special meaning is that character)
+fn js_string_literal_value(literal: &str, quote: char) -> String {
+    let inner = literal
+        .strip_prefix(quote)
+        .and_then(|s| s.strip_suffix(quote))
+        .unwrap();
+    let mut value = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            assert_ne!(c, quote, "unescaped quote in {literal}");
+            value.push(c);
+            continue;
+        }
+        match chars.next().unwrap() {
+            'n' => value.push('\n'),
+            't' => value.push('\t'),
+            'r' => value.push('\r'),
+            '0' => value.push('\0'),
+            c @ ('x' | 'u' | '1'..='9') => panic!("unsupported escape \\{c} in {literal}"),
+            c => value.push(c),
+        }
+    }
+    value
+}
+
+fn nested_document() -> Document {
+    fun_html::html(
+        [attr::lang("en")],
+        [
+            elt::head(
+                [],
+                [
+                    elt::meta_charset_utf8(),
+                    elt::title([], "Tom & Jerry"),
+                    elt::script([], "if (a < b) { alert('</script>'); }"),
+                ],
+            ),
+            elt::body(
+                [attr::class(["main"])],
+                [
+                    elt::h1([attr::placeholder("<none>")], ["<Hello>".into()]),
+                    elt::table(
+                        [],
+                        [elt::tr(
+                            [Attribute::new_value("data-index", 1)],
+                            [elt::td([], ["a \"quoted\" cell".into()]), elt::td([], [])],
+                        )],
+                    ),
+                    elt::input([attr::required(), attr::value("\"é\" & ü")]),
+                    elt::raw("<custom-element></custom-element>"),
+                ],
+            ),
+        ],
+    )
+}
+
+#[test]
+fn write_to_should_render_the_same_as_to_string() {
+    let doc = nested_document();
+    let mut string = String::new();
+    doc.write_to(&mut string).unwrap();
+    assert_eq!(string, doc.to_string());
+}
+
+#[test]
+fn element_write_to_should_render_the_same_as_to_string() {
+    let element = elt::div([attr::id("a&b")], [elt::p([], ["x < y".into()])]);
+    let mut string = String::new();
+    element.write_to(&mut string).unwrap();
+    assert_eq!(string, element.to_string());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn write_to_io_should_render_the_same_as_to_string() {
+    let doc = nested_document();
+    let mut bytes = Vec::new();
+    doc.write_to_io(&mut bytes).unwrap();
+    assert_eq!(bytes, doc.to_string().into_bytes());
+
+    let element = elt::div([attr::id("a&b")], [elt::p([], ["x < y".into()])]);
+    let mut bytes = Vec::new();
+    element.write_to_io(&mut bytes).unwrap();
+    assert_eq!(bytes, element.to_string().into_bytes());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn write_to_io_should_return_the_io_error() {
+    struct FailingWriter;
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "oops"))
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    let error = nested_document()
+        .write_to_io(&mut FailingWriter)
+        .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::BrokenPipe);
+}
+
+fn document_with_tables_and_pre() -> Document {
+    fun_html::html(
+        [attr::lang("en")],
+        [
+            elt::head([], [elt::title([], "Report")]),
+            elt::body(
+                [],
+                [
+                    elt::h1([], ["Report ".into(), elt::small([], ["v2".into()])]),
+                    elt::table(
+                        [],
+                        [elt::tr(
+                            [],
+                            [
+                                elt::td([], ["a".into()]),
+                                elt::td(
+                                    [],
+                                    [elt::table([], [elt::tr([], [elt::td([], ["b".into()])])])],
+                                ),
+                            ],
+                        )],
+                    ),
+                    Element::new(
+                        "pre",
+                        [],
+                        [
+                            "  fn main() {\n      <code>\n  }".into(),
+                            elt::div([], ["x".into()]),
+                        ],
+                    ),
+                    elt::br([]),
+                ],
+            ),
+        ],
+    )
+}
+
+#[test]
+fn should_render_pretty_document() {
+    assert_eq!(
+        document_with_tables_and_pre().to_string_pretty(),
+        r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <title>Report</title>
+  </head>
+  <body>
+    <h1>Report <small>v2</small></h1>
+    <table>
+      <tr>
+        <td>a</td>
+        <td>
+          <table>
+            <tr>
+              <td>b</td>
+            </tr>
+          </table>
+        </td>
+      </tr>
+    </table>
+    <pre>  fn main() {
+      &lt;code&gt;
+  }<div>x</div></pre>
+    <br>
+  </body>
+</html>"#
+    );
+}
+
+#[test]
+fn pretty_rendering_should_not_change_pre_content() {
+    fn pre_content(html: &str) -> &str {
+        let start = html.find("<pre>").unwrap();
+        let end = html.find("</pre>").unwrap();
+        &html[start..end]
+    }
+    let doc = document_with_tables_and_pre();
+    assert_eq!(
+        pre_content(&doc.to_string_pretty()),
+        pre_content(&doc.to_string())
+    );
+}
+
+#[rstest]
+#[case(elt::none(), "")]
+#[case(elt::text("hello"), "hello")]
+#[case(elt::p([], ["hello ".into(), elt::span([], ["world".into()])]), "<p>hello <span>world</span></p>")]
+#[case(
+    [elt::div([], []), elt::div([], [])].into(),
+    "<div></div>\n<div></div>"
+)]
+#[case(
+    elt::ul([], [elt::li([], ["a".into()]), elt::none(), [elt::li([], ["b".into()])].into()]),
+    "<ul>\n  <li>a</li>\n  <li>b</li>\n</ul>"
+)]
+#[case(
+    elt::textarea([], "  keep\n  this"),
+    "<textarea>  keep\n  this</textarea>"
+)]
+fn should_render_pretty_element(#[case] element: Element, #[case] expected: &str) {
+    assert_eq!(element.to_string_pretty(), expected);
+}
+
+#[test]
+fn should_render_fragment_without_doctype_nor_wrappers() {
+    let html = fun_html::render_fragment([
+        elt::div([attr::class(["a&b"])], ["<hello>".into()]),
+        elt::br([]),
+    ]);
+    assert_eq!(html, "<div class=\"a&amp;b\">&lt;hello&gt;</div><br>");
+    assert!(!html.contains("DOCTYPE"));
+    assert!(!html.contains("<html") && !html.contains("<body"));
+}
+
+#[test]
+fn element_display_should_not_render_doctype() {
+    let element = elt::p([], ["hello".into()]);
+    assert_eq!(element.to_string(), "<p>hello</p>");
+    assert_eq!(
+        fun_html::render_fragment([element.clone()]),
+        element.to_string()
+    );
+}
+
+#[test]
+fn document_bytes_should_be_the_same_as_string_bytes() {
+    let doc = document_with_tables_and_pre();
+    assert_eq!(doc.clone().into_bytes(), doc.to_string().into_bytes());
+}
+
+#[test]
+fn write_bytes_should_append_to_buffer() {
+    let doc = nested_document();
+    let mut buf = b"prefix".to_vec();
+    doc.write_bytes(&mut buf);
+    assert_eq!(buf, format!("prefix{doc}").into_bytes());
+}
+
+#[test]
+fn into_bytes_should_reserve_a_capacity_close_to_the_length() {
+    let bytes = document_with_tables_and_pre().into_bytes();
+    assert!(
+        bytes.capacity() <= bytes.len() * 3 / 2,
+        "capacity {} is much larger than length {}",
+        bytes.capacity(),
+        bytes.len()
+    );
+}
+
+fn large_table() -> Document {
+    fun_html::html(
+        [],
+        [
+            elt::head([], [elt::title([], "Report")]),
+            elt::body(
+                [],
+                [elt::table(
+                    [],
+                    (0..500).map(|i| {
+                        elt::tr(
+                            [attr::class(["row"])],
+                            [
+                                elt::td([], [elt::text(format!("{i}"))]),
+                                elt::td([], ["<é & ü>".into()]),
+                            ],
+                        )
+                    }),
+                )],
+            ),
+        ],
+    )
+}
+
+#[rstest]
+fn concatenated_chunks_should_be_the_same_as_string(
+    #[values(0, 1, 7, 64, 1000, 100_000)] chunk_len: usize,
+) {
+    let doc = large_table();
+    let expected = doc.to_string();
+    let chunks: Vec<String> = doc.render_chunks(chunk_len).collect();
+    assert_eq!(chunks.concat(), expected);
+    assert!(chunks.iter().all(|chunk| !chunk.is_empty()));
+}
+
+#[test]
+fn chunks_should_not_exceed_chunk_len() {
+    let chunks: Vec<String> = large_table().render_chunks(64).collect();
+    assert!(chunks.len() > 100);
+    for chunk in &chunks {
+        assert!(chunk.len() <= 64, "chunk too long: {chunk:?}");
+        assert!(!chunk.ends_with('&') && !chunk.ends_with("&lt"));
+    }
+}
+
+#[test]
+fn oversized_text_should_be_rendered_in_a_single_chunk() {
+    let text = "a".repeat(100);
+    let doc = fun_html::html([], [elt::text(text.clone())]);
+    let chunks: Vec<String> = doc.render_chunks(10).collect();
+    assert!(chunks.contains(&text));
+}
+
+#[test]
+fn default_render_options_should_render_like_display() {
+    let doc = document_with_tables_and_pre();
+    assert_eq!(doc.render_with(&RenderOptions::default()), doc.to_string());
+    let element = elt::div([], [elt::br([]), "hello".into()]);
+    assert_eq!(
+        element.render_with(&RenderOptions::new()),
+        element.to_string()
+    );
+}
+
+#[rstest]
+#[case(RenderOptions::new().pretty(true), "<div>\n  <p>a<br></p>\n  <hr>\n</div>")]
+#[case(RenderOptions::new().pretty(true).indent_width(4), "<div>\n    <p>a<br></p>\n    <hr>\n</div>")]
+#[case(RenderOptions::new().pretty(true).indent_width(0), "<div>\n<p>a<br></p>\n<hr>\n</div>")]
+#[case(RenderOptions::new().indent_width(4), "<div><p>a<br></p><hr></div>")]
+#[case(RenderOptions::new().self_close_void(true), "<div><p>a<br /></p><hr /></div>")]
+#[case(RenderOptions::new().doctype(true), "<!DOCTYPE html>\n<div><p>a<br></p><hr></div>")]
+#[case(RenderOptions::new().doctype(false), "<div><p>a<br></p><hr></div>")]
+#[case(
+    RenderOptions::new().pretty(true).indent_width(1).self_close_void(true).doctype(true),
+    "<!DOCTYPE html>\n<div>\n <p>a<br /></p>\n <hr />\n</div>"
+)]
+fn should_render_element_with_options(#[case] options: RenderOptions, #[case] expected: &str) {
+    let element = elt::div([], [elt::p([], ["a".into(), elt::br([])]), elt::hr([])]);
+    assert_eq!(element.render_with(&options), expected);
+}
+
+#[rstest]
+#[case(
+    RenderOptions::new(),
+    "<!DOCTYPE html>\n<html><head><meta charset=\"UTF-8\"></head><body></body></html>"
+)]
+#[case(RenderOptions::new().doctype(false), "<html><head><meta charset=\"UTF-8\"></head><body></body></html>")]
+#[case(
+    RenderOptions::new().pretty(true).self_close_void(true),
+    "<!DOCTYPE html>\n<html>\n  <head>\n    <meta charset=\"UTF-8\" />\n  </head>\n  <body></body>\n</html>"
+)]
+fn should_render_document_with_options(#[case] options: RenderOptions, #[case] expected: &str) {
+    let doc = fun_html::html(
+        [],
+        [elt::head([], [elt::meta_charset_utf8()]), elt::body([], [])],
+    );
+    assert_eq!(doc.render_with(&options), expected);
+}
+
+#[test]
+fn self_close_void_should_apply_inside_whitespace_sensitive_elements() {
+    let element = Element::new("pre", [], ["a".into(), elt::br([])]);
+    let options = RenderOptions::new().pretty(true).self_close_void(true);
+    assert_eq!(element.render_with(&options), "<pre>a<br /></pre>");
+}
+
+#[rstest]
+#[case(elt::br([]), "<br>", "<br />")]
+#[case(
+    elt::img([attr::src("a.png"), attr::alt("A")]),
+    "<img src=\"a.png\" alt=\"A\">",
+    "<img src=\"a.png\" alt=\"A\" />"
+)]
+#[case(
+    elt::meta_charset_utf8(),
+    "<meta charset=\"UTF-8\">",
+    "<meta charset=\"UTF-8\" />"
+)]
+#[case(
+    elt::input([attr::type_checkbox(), attr::required()]),
+    "<input type=\"checkbox\" required>",
+    "<input type=\"checkbox\" required=\"required\" />"
+)]
+#[case(
+    elt::div([attr::hidden()], ["a < b".into()]),
+    "<div hidden>a &lt; b</div>",
+    "<div hidden=\"hidden\">a &lt; b</div>"
+)]
+#[case(elt::raw("<br>"), "<br>", "<br>")]
+fn should_render_xhtml(#[case] element: Element, #[case] html: &str, #[case] xhtml: &str) {
+    assert_eq!(element.to_string(), html);
+    assert_eq!(element.to_xhtml_string(), xhtml);
+    assert_eq!(
+        element.render_with(&RenderOptions::new().xhtml(true)),
+        xhtml
+    );
+}
+
+#[test]
+fn should_render_xhtml_document() {
+    let doc = fun_html::html(
+        [],
+        [
+            elt::head([], [elt::meta_charset_utf8()]),
+            elt::body([], [elt::br([])]),
+        ],
+    );
+    assert_eq!(
+        doc.to_xhtml_string(),
+        "<!DOCTYPE html>\n<html><head><meta charset=\"UTF-8\" /></head><body><br /></body></html>"
+    );
+}
+
+struct Badge(&'static str);
+
+impl fun_html::Render for Badge {
+    fn render(self) -> Element {
+        elt::span([attr::class(["badge"])], [self.0.into()])
+    }
+}
+
+struct UserCard {
+    name: String,
+    badges: Vec<Badge>,
+}
+
+impl fun_html::Render for UserCard {
+    fn render(self) -> Element {
+        elt::div(
+            [attr::class(["user-card"])],
+            [
+                elt::text(self.name),
+                self.badges
+                    .into_iter()
+                    .map(Element::from)
+                    .collect::<Vec<_>>()
+                    .into(),
+            ],
+        )
+    }
+}
+
+#[test]
+fn should_render_components_nested_in_children() {
+    let card = UserCard {
+        name: "Alice".into(),
+        badges: vec![Badge("admin"), Badge("<new>")],
+    };
+    let element = elt::ul([], [elt::li([], [card.into()]), Badge("solo").into()]);
+    assert_eq!(
+        element.to_string(),
+        "<ul><li><div class=\"user-card\">Alice<span class=\"badge\">admin</span><span class=\"badge\">&lt;new&gt;</span></div></li><span class=\"badge\">solo</span></ul>"
+    );
+}
+
+#[rstest]
+#[case(attr::required())]
+#[case(attr::id("foo"))]
+#[case(attr::placeholder("say \"hi\" & <bye>"))]
+#[case(attr::colspan(2))]
+fn attribute_display_should_be_the_same_as_in_element(#[case] attribute: Attribute) {
+    let element = elt::div([attribute.clone()], []);
+    assert_eq!(element.to_string(), format!("<div {attribute}></div>"));
+}
+
+#[rstest]
+#[case("a < b", "a &lt; b")]
+#[case("\"quoted\" & 'single'", "\"quoted\" &amp; 'single'")]
+fn text_display_should_be_escaped(#[case] text: &'static str, #[case] expected: &str) {
+    assert_eq!(elt::text(text).to_string(), expected);
+    assert_eq!(
+        elt::p([], [elt::text(text)]).to_string(),
+        format!("<p>{expected}</p>")
+    );
+}
+
+#[test]
+fn document_render_into_should_append_to_buffer() {
+    let doc = document_with_tables_and_pre();
+    let mut buffer = String::from("prefix");
+    doc.render_into(&mut buffer);
+    assert_eq!(buffer, format!("prefix{doc}"));
+    assert!(buffer.capacity() <= buffer.len() * 3 / 2);
+}
+
+#[test]
+fn element_render_into_should_append_to_buffer() {
+    let element = elt::ul([], [elt::li([attr::class(["a"])], ["<1>".into()])]);
+    let mut buffer = String::from("prefix");
+    element.render_into(&mut buffer);
+    element.render_into(&mut buffer);
+    assert_eq!(buffer, format!("prefix{element}{element}"));
+}
+
+#[test]
+fn document_new_should_be_the_same_as_html_without_attributes() {
+    let head = elt::head([], [elt::title([], "Title")]);
+    let body = elt::body([], [elt::p([], ["<content>".into()])]);
+    assert_eq!(
+        Document::new(head.clone(), body.clone()).to_string(),
+        fun_html::html([], [head, body]).to_string()
+    );
+}
+
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>";
+const LEGACY_DOCTYPE: &str = "<!DOCTYPE HTML PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \"http://www.w3.org/TR/html4/strict.dtd\">";
+
+#[rstest]
+#[case(Document::default(), "<!DOCTYPE html>\n")]
+#[case(Document::default().without_doctype(), "")]
+#[case(Document::default().with_doctype(LEGACY_DOCTYPE), "<!DOCTYPE HTML PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \"http://www.w3.org/TR/html4/strict.dtd\">\n")]
+#[case(Document::default().with_xml_declaration(), "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE html>\n")]
+#[case(Document::default().with_doctype(LEGACY_DOCTYPE).with_xml_declaration(), "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE HTML PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \"http://www.w3.org/TR/html4/strict.dtd\">\n")]
+#[case(Document::default().with_xml_declaration().without_doctype(), "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")]
+fn should_render_document_prologue(#[case] doc: Document, #[case] prologue: &str) {
+    let expected = format!("{prologue}<html><head></head><body></body></html>");
+    assert_eq!(doc.to_string(), expected);
+    assert_eq!(doc.render_with(&RenderOptions::new()), expected);
+    assert_eq!(doc.clone().into_bytes(), expected.as_bytes());
+    assert_eq!(doc.clone().render_chunks(8).collect::<String>(), expected);
+    assert_eq!(
+        doc.to_string().matches(XML_DECLARATION).count(),
+        usize::from(prologue.contains(XML_DECLARATION))
+    );
+    assert_eq!(
+        doc.to_string().matches("<!DOCTYPE").count(),
+        usize::from(prologue.contains("<!DOCTYPE"))
+    );
+}
+
+#[test]
+fn doctype_render_option_should_only_remove_the_doctype() {
+    let doc = Document::default().with_xml_declaration();
+    assert_eq!(
+        doc.render_with(&RenderOptions::new().doctype(false)),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<html><head></head><body></body></html>"
+    );
+}
+
+#[test]
+fn should_add_html_attributes_to_document() {
+    let doc = fun_html::html([attr::lang("en")], [elt::head([], []), elt::body([], [])])
+        .with_html_attrs([Attribute::new("dir", "rtl"), attr::none()])
+        .with_html_attrs([attr::class(["dark"])]);
+    assert_eq!(
+        doc.to_string(),
+        "<!DOCTYPE html>\n<html lang=\"en\" dir=\"rtl\" class=\"dark\"><head></head><body></body></html>"
+    );
+}
+
+#[rstest]
+#[case(Document::default().with_lang("en").with_dir(Dir::Rtl), "<!DOCTYPE html>\n<html lang=\"en\" dir=\"rtl\">")]
+#[case(Document::default().with_dir(Dir::Rtl).with_lang("en"), "<!DOCTYPE html>\n<html dir=\"rtl\" lang=\"en\">")]
+#[case(Document::default().with_lang("en").without_doctype().with_dir(Dir::Ltr), "<html lang=\"en\" dir=\"ltr\">")]
+#[case(
+    Document::default().with_xml_declaration().with_lang("fr"),
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE html>\n<html lang=\"fr\">"
+)]
+#[case(
+    Document::new(elt::head([], []), elt::body([], [])).with_dir(Dir::Auto).with_html_attribute(attr::class(["dark"])),
+    "<!DOCTYPE html>\n<html dir=\"auto\" class=\"dark\">"
+)]
+#[case(fun_html::html([attr::lang("en")], []).with_lang("de"), "<!DOCTYPE html>\n<html lang=\"de\">")]
+fn should_set_lang_and_dir_on_html_element(#[case] doc: Document, #[case] expected_start: &str) {
+    let rendered = doc.to_string();
+    assert!(
+        rendered.starts_with(expected_start),
+        "{rendered:?} does not start with {expected_start:?}"
+    );
+}
+
+#[rstest]
+#[case(elt::comment(" build 42 "), "<!-- build 42 -->")]
+#[case(
+    elt::comment("line 1\n  line 2 <b> & more"),
+    "<!--line 1\n  line 2 <b> & more-->"
+)]
+#[case(elt::comment(""), "<!---->")]
+#[case(elt::div([], [elt::comment("a"), "b".into()]), "<div><!--a-->b</div>")]
+fn should_render_comment(#[case] element: Element, #[case] expected: &str) {
+    assert_eq!(element.to_string(), expected);
+}
+
+#[rstest]
+#[case(">a", InvalidComment::StartsWithGreaterThan)]
+#[case("->a", InvalidComment::StartsWithGreaterThan)]
+#[case("a -- b", InvalidComment::ContainsDoubleDash)]
+#[case("<!-- nested", InvalidComment::ContainsDoubleDash)]
+#[case("a -->", InvalidComment::ContainsDoubleDash)]
+#[case("a-", InvalidComment::EndsWithDash)]
+fn should_reject_invalid_comment(#[case] text: &'static str, #[case] expected: InvalidComment) {
+    assert_eq!(elt::comment_checked(text).unwrap_err(), expected);
+}
+
+#[rstest]
+#[cfg(not(debug_assertions))]
+#[case(
+    String::from("--><script>alert(1)</script><!--"),
+    "<!--- -><script>alert(1)</script><!- - -->"
+)]
+#[case(String::from(">a"), "<!-- >a-->")]
+#[case(String::from("->a"), "<!-- ->a-->")]
+#[case(String::from("a---b"), "<!--a- - -b-->")]
+#[case(String::from("a-"), "<!--a- -->")]
+fn invalid_comment_should_be_sanitized_in_release_builds(
+    #[case] text: String,
+    #[case] expected: &str,
+) {
+    assert_eq!(elt::comment(text).to_string(), expected);
+}
+
+#[rstest]
+#[case("a - b")]
+#[case("multi\nline\ncomment")]
+#[case("a > b")]
+fn should_accept_valid_comment(#[case] text: &'static str) {
+    assert_eq!(
+        elt::comment_checked(text).unwrap().to_string(),
+        format!("<!--{text}-->")
+    );
+}
+
+#[rstest]
+#[case(elt::none())]
+#[case(elt::text("é < ü"))]
+#[case(elt::div([attr::class(["a\"b"])], [elt::br([]), elt::comment("c")]))]
+fn element_bytes_should_be_the_same_as_string_bytes(#[case] element: Element) {
+    assert_eq!(element.render_to_bytes(), element.to_string().into_bytes());
+}
+
+fn counting_lazy_element() -> (Element, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+    use std::sync::{atomic::AtomicUsize, atomic::Ordering, Arc};
+    let count = Arc::new(AtomicUsize::new(0));
+    let element = {
+        let count = Arc::clone(&count);
+        elt::lazy(move || {
+            count.fetch_add(1, Ordering::SeqCst);
+            elt::p([], ["<lazy>".into()])
+        })
+    };
+    (element, count)
+}
+
+#[test]
+fn lazy_element_should_be_built_once_per_rendering() {
+    use std::sync::atomic::Ordering;
+    let (lazy, count) = counting_lazy_element();
+    let element = elt::div([], [lazy]);
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+    assert_eq!(element.to_string(), "<div><p>&lt;lazy&gt;</p></div>");
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert_eq!(element.to_string_pretty(), "<div><p>&lt;lazy&gt;</p></div>");
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+    let doc = fun_html::html([], [elt::body([], [element.clone()])]);
+    assert_eq!(
+        doc.clone().render_chunks(4).collect::<String>(),
+        doc.to_string()
+    );
+    assert_eq!(count.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn lazy_element_should_not_be_built_if_not_rendered() {
+    use std::sync::atomic::Ordering;
+    let (lazy, count) = counting_lazy_element();
+    let element = elt::div([], [lazy]);
+    drop(element.clone());
+    drop(element);
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn element_should_be_written_with_write_macro() {
+    use std::fmt::Write;
+    let mut out = String::new();
+    write!(out, "<main>{}</main>", elt::p([], ["a & b".into()])).unwrap();
+    assert_eq!(out, "<main><p>a &amp; b</p></main>");
+}
+
+#[test]
+fn element_debug_should_show_rendered_html() {
+    let element = elt::div(
+        [attr::id("main")],
+        [elt::ul(
+            [],
+            [elt::li([], ["a".into()]), elt::li([], ["b".into()])],
+        )],
+    );
+    assert_eq!(
+        format!("{element:?}"),
+        "<div id=\"main\"><ul><li>a</li><li>b</li></ul></div>"
+    );
+    assert_eq!(
+        format!("{element:#?}"),
+        "<div id=\"main\">\n  <ul>\n    <li>a</li>\n    <li>b</li>\n  </ul>\n</div>"
+    );
+}
+
+#[test]
+fn document_debug_should_show_rendered_html() {
+    let doc = Document::default();
+    assert_eq!(format!("{doc:?}"), doc.to_string());
+    assert_eq!(format!("{doc:#?}"), doc.to_string_pretty());
+}
+
+#[test]
+fn attribute_debug_should_show_rendered_attribute() {
+    assert_eq!(format!("{:?}", attr::id("a\"b")), "id=\"a&quot;b\"");
+    assert_eq!(format!("{:?}", attr::required()), "required");
+}
+
+#[test]
+fn inner_debug_should_show_structure() {
+    let element = elt::p([attr::required()], ["text".into()]);
+    let debug = format!("{:?}", element.inner_debug());
+    assert!(debug.starts_with("Parent { tag: \"p\""), "{debug}");
+    assert!(debug.contains("Flag(\"required\")"), "{debug}");
+    assert!(debug.contains("Text(\"text\")"), "{debug}");
+    assert_eq!(
+        format!("{:?}", attr::required().inner_debug()),
+        "Flag(\"required\")"
+    );
+}
+
+#[rstest]
+#[case(elt::none(), "<!DOCTYPE html>\n")]
+#[case(elt::div([], [elt::br([])]), "<!DOCTYPE html>\n<div><br></div>")]
+fn should_render_element_with_doctype(#[case] element: Element, #[case] expected: &str) {
+    assert_eq!(element.to_string_with_doctype(), expected);
+    assert!(!element.to_string().contains("DOCTYPE"));
+}
+
+#[rstest]
+#[case(elt::fragment([]), "")]
+#[case(elt::fragment([elt::text("a"), elt::br([])]), "a<br>")]
+#[case(
+    elt::fragment([
+        elt::text("1"),
+        elt::fragment([elt::text("2"), elt::fragment([elt::text("3")]), elt::none()]),
+        elt::text("4"),
+    ]),
+    "1234"
+)]
+#[case(
+    elt::head([], [elt::fragment([elt::meta_charset_utf8(), elt::fragment([elt::title([], "T")])])]),
+    "<head><meta charset=\"UTF-8\"><title>T</title></head>"
+)]
+#[case(fun_html::conv::fragment([fun_html::conv::h1(["a".into()]), fun_html::conv::hr()]), "<h1>a</h1><hr>")]
+fn should_render_fragment(#[case] element: Element, #[case] expected: &str) {
+    assert_eq!(element.to_string(), expected);
+}
+
+#[test]
+fn nested_fragments_should_be_flattened_when_pretty_printed() {
+    let list = elt::ul(
+        [],
+        [elt::fragment([
+            elt::li([], ["a".into()]),
+            elt::fragment([elt::li([], ["b".into()])]),
+        ])],
+    );
+    assert_eq!(
+        list.to_string_pretty(),
+        "<ul>\n  <li>a</li>\n  <li>b</li>\n</ul>"
+    );
+}
+
+#[rstest]
+#[case([attr::class(["a"]), attr::class(["b", "c"])], "<div class=\"a b c\"></div>")]
+#[case([attr::class(["a"]), attr::id("x"), attr::class([])], "<div class=\"a\" id=\"x\"></div>")]
+#[case([attr::class([" a "]), attr::class(["<b>"])], "<div class=\"a &lt;b&gt;\"></div>")]
+#[case([attr::style("color: red;"), attr::style("margin: 0")], "<div style=\"color: red; margin: 0\"></div>")]
+#[case([attr::style("color: red"), attr::style(""), attr::style("margin: 0;")], "<div style=\"color: red; margin: 0\"></div>")]
+#[case([attr::style("  color: red ; "), attr::style(" margin: 0 ")], "<div style=\"color: red; margin: 0\"></div>")]
+#[case([attr::class(["  a  "]), attr::style(" b "), attr::class([" c "])], "<div class=\"a c\" style=\" b \"></div>")]
+#[case([attr::id("a"), attr::id("b")], "<div id=\"b\"></div>")]
+#[case([attr::id("a"), attr::class(["c"]), attr::id("b"), attr::id("c")], "<div id=\"c\" class=\"c\"></div>")]
+#[case([attr::hidden(), attr::hidden()], "<div hidden></div>")]
+#[case([attr::hidden(), Attribute::new("hidden", "until-found")], "<div hidden=\"until-found\"></div>")]
+#[case([Attribute::new("hidden", "until-found"), attr::hidden()], "<div hidden></div>")]
+#[case([attr::required(), attr::none(), attr::required()], "<div required></div>")]
+#[case([Attribute::new_flag("class"), attr::class(["a"])], "<div class=\"a\"></div>")]
+fn should_merge_or_deduplicate_repeated_attributes<const N: usize>(
+    #[case] attributes: [Attribute; N],
+    #[case] expected: &str,
+) {
+    assert_eq!(elt::div(attributes, []).to_string(), expected);
+}
+
+#[test]
+fn document_accessors_should_return_head_and_body() {
+    let head = elt::head([], [elt::title([], "T")]);
+    let body = elt::body([attr::class(["b"])], ["<hello>".into()]);
+    let doc = Document::new(head.clone(), body.clone()).with_html_attrs([attr::lang("en")]);
+    assert_eq!(doc.head().unwrap().to_string(), head.to_string());
+    assert_eq!(doc.body().unwrap().to_string(), body.to_string());
+    assert_eq!(doc.render_head(), head.to_string());
+    assert_eq!(doc.render_body(), body.to_string());
+}
+
+#[test]
+fn document_without_head_nor_body_should_have_no_accessors() {
+    let doc = fun_html::html([], [elt::p([], [])]);
+    assert!(doc.head().is_none());
+    assert!(doc.body().is_none());
+    assert_eq!(doc.render_head(), "");
+    assert_eq!(doc.render_body(), "");
+    assert_eq!(doc.render_with_cached_head("ignored"), doc.to_string());
+}
+
+#[test]
+fn render_with_cached_head_should_be_identical_to_full_rendering() {
+    let head = elt::head([], [elt::meta_charset_utf8(), elt::title([], "Site & co")]);
+    let cached = Document::new(head.clone(), elt::body([], [])).render_head();
+    for i in 0..3 {
+        let doc = Document::new(
+            head.clone(),
+            elt::body([], [elt::p([], [elt::text(format!("page {i}"))])]),
+        )
+        .with_html_attrs([attr::lang("en")])
+        .with_xml_declaration();
+        assert_eq!(doc.render_with_cached_head(&cached), doc.to_string());
+    }
+}
+
+#[rstest]
+#[case(elt::div([], []).with_attribute(attr::id("main")), "<div id=\"main\"></div>")]
+#[case(
+    elt::div([attr::id("main")], [elt::text("a")]).with_attribute(attr::class(["big"])).with_attribute(attr::hidden()),
+    "<div id=\"main\" class=\"big\" hidden>a</div>"
+)]
+#[case(
+    elt::div([attr::class(["a"])], []).with_attributes([attr::class(["b"]), attr::id("x")]).with_attributes([]),
+    "<div class=\"a b\" id=\"x\"></div>"
+)]
+#[case(elt::br([]).with_attribute(attr::id("x")), "<br id=\"x\">")]
+#[case(elt::img([attr::src("a.png")]).with_attributes([attr::width_int(10)]), "<img src=\"a.png\" width=\"10\">")]
+#[case(elt::text("a").with_attribute(attr::id("x")), "a")]
+#[case(elt::raw("<b>").with_attribute(attr::id("x")), "<b>")]
+#[case(elt::none().with_attribute(attr::id("x")), "")]
+fn should_add_attributes_to_existing_element(#[case] element: Element, #[case] expected: &str) {
+    assert_eq!(element.to_string(), expected);
+}
+
+#[test]
+fn should_build_list_by_appending_children_in_a_loop() {
+    let mut list = elt::ul([attr::id("list")], []);
+    for i in 1..=3 {
+        list = list.with_child(elt::li([], [elt::text(i.to_string())]));
+    }
+    assert_eq!(
+        list.to_string(),
+        "<ul id=\"list\"><li>1</li><li>2</li><li>3</li></ul>"
+    );
+}
+
+#[rstest]
+#[case(elt::div([], [elt::text("a")]).with_children([elt::br([]), elt::text("b")]), "<div>a<br>b</div>")]
+#[case(elt::div([], []).with_children([]).with_child(elt::hr([])), "<div><hr></div>")]
+#[case(elt::fragment([elt::text("a")]).with_child(elt::text("b")), "ab")]
+#[case(elt::none().with_child(elt::text("b")), "b")]
+#[case(elt::text("a").with_child(elt::br([])), "a<br>")]
+#[case(elt::raw("<b>a</b>").with_children([elt::text("c"), elt::text("d")]), "<b>a</b>cd")]
+fn should_append_children_to_existing_element(#[case] element: Element, #[case] expected: &str) {
+    assert_eq!(element.to_string(), expected);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn appending_children_to_void_element_should_be_a_no_op_in_release() {
+    assert_eq!(elt::br([]).with_child(elt::text("a")).to_string(), "<br>");
+}
+
+#[rstest]
+#[case(Document::new(elt::head([], [elt::title([], "Old"), elt::meta_charset_utf8()]), elt::body([], [])), "<head><title>New</title><meta charset=\"UTF-8\"></head>")]
+#[case(Document::new(elt::head([], [elt::meta_charset_utf8()]), elt::body([], [])), "<head><meta charset=\"UTF-8\"><title>New</title></head>")]
+#[case(Document::default(), "<head><title>New</title></head>")]
+#[case(fun_html::html([], [elt::body([], [])]), "<head><title>New</title></head>")]
+fn set_title_should_replace_existing_title_or_append_it(
+    #[case] mut doc: Document,
+    #[case] expected_head: &str,
+) {
+    doc.set_title("New");
+    assert_eq!(doc.render_head(), expected_head);
+}
+
+#[test]
+fn set_title_should_escape_and_replace_only_the_first_title() {
+    let mut doc = Document::new(
+        elt::head([], [elt::title([], "a"), elt::title([], "b")]),
+        elt::body([], []),
+    );
+    doc.set_title("<New>");
+    doc.set_title("<Newer>");
+    assert_eq!(
+        doc.render_head(),
+        "<head><title>&lt;Newer&gt;</title><title>b</title></head>"
+    );
+}
+
+#[test]
+fn added_head_and_body_elements_should_keep_insertion_order() {
+    let mut doc = Document::new(
+        elt::head([], [elt::meta_charset_utf8()]),
+        elt::body([], [elt::p([], ["Hello".into()])]),
+    );
+    doc.add_to_head(elt::link_stylesheet("/a.css"));
+    doc.add_to_body_end(elt::script([attr::src("/a.js")], ""));
+    doc.add_to_head(elt::link_stylesheet("/b.css"));
+    doc.add_to_body_end(elt::script([attr::src("/b.js")], ""));
+    assert_eq!(
+        doc.to_string(),
+        concat!(
+            "<!DOCTYPE html>\n<html>",
+            "<head><meta charset=\"UTF-8\"><link rel=\"stylesheet\" href=\"/a.css\"><link rel=\"stylesheet\" href=\"/b.css\"></head>",
+            "<body><p>Hello</p><script src=\"/a.js\"></script><script src=\"/b.js\"></script></body>",
+            "</html>"
+        )
+    );
+}
+
+#[test]
+fn should_create_missing_head_and_body_when_adding_to_them() {
+    let mut doc = fun_html::html([attr::lang("en")], []);
+    doc.add_to_body_end(elt::p([], ["Hello".into()]));
+    doc.add_to_head(elt::title([], "Title"));
+    assert_eq!(
+        doc.to_string(),
+        "<!DOCTYPE html>\n<html lang=\"en\"><head><title>Title</title></head><body><p>Hello</p></body></html>"
+    );
+}
+
+#[rstest]
+#[case("a < b & \"c\"".into(), "a &lt; b &amp; \"c\"")]
+#[case(String::from("<script>alert(1)</script>").into(), "&lt;script&gt;alert(1)&lt;/script&gt;")]
+#[case(Cow::<'static, str>::Borrowed("<b>").into(), "&lt;b&gt;")]
+#[case(Cow::<'static, str>::Owned("<i>".into()).into(), "&lt;i&gt;")]
+fn strings_converted_into_element_should_be_escaped(
+    #[case] element: Element,
+    #[case] expected: &str,
+) {
+    assert_eq!(element.to_string(), expected);
+}
+
+#[test]
+fn string_children_should_infer_element_type_in_array_literals() {
+    let name = String::from("<Jo>");
+    let element = elt::p(
+        [],
+        [
+            "Hello ".into(),
+            name.into(),
+            elt::br([]),
+            Cow::Borrowed("!").into(),
+        ],
+    );
+    assert_eq!(element.to_string(), "<p>Hello &lt;Jo&gt;<br>!</p>");
+}
+
+#[rstest]
+#[case(None::<Element>.into(), "")]
+#[case(Some(elt::br([])).into(), "<br>")]
+#[case(elt::div([], [None::<Element>.into()]), "<div></div>")]
+#[case(elt::p([], ["a".into(), None::<Element>.into(), "b".into()]), "<p>ab</p>")]
+#[case(elt::p([], ["a".into(), Some(elt::text("-")).into(), "b".into()]), "<p>a-b</p>")]
+fn optional_element_should_render_nothing_when_none(
+    #[case] element: Element,
+    #[case] expected: &str,
+) {
+    assert_eq!(element.to_string(), expected);
+    assert_eq!(element.render_to_bytes().len(), expected.len());
+}
+
+#[test]
+fn none_should_not_introduce_whitespace_between_siblings_when_pretty_printed() {
+    let element = elt::ul(
+        [],
+        [
+            elt::li([], ["a".into()]),
+            None::<Element>.into(),
+            elt::li([], ["b".into()]),
+        ],
+    );
+    assert_eq!(
+        element.to_string_pretty(),
+        "<ul>\n  <li>a</li>\n  <li>b</li>\n</ul>"
+    );
+}
+
+#[rstest]
+#[case(elt::none())]
+#[case(elt::div([attr::id("a")], [elt::text("<b>"), elt::br([])]))]
+#[case(elt::fragment([elt::text("a"), elt::comment("b")]))]
+fn element_into_string_should_be_the_same_as_to_string(#[case] element: Element) {
+    let expected = element.to_string();
+    assert_eq!(String::from(element.clone()), expected);
+    let string: String = element.into();
+    assert_eq!(string, expected);
+}
+
+#[test]
+fn document_into_string_should_be_the_same_as_to_string() {
+    let doc = nested_document().with_xml_declaration();
+    let expected = doc.to_string();
+    assert_eq!(String::from(doc.clone()), expected);
+    let string: String = doc.into();
+    assert_eq!(string, expected);
+}
+
+#[test]
+fn collecting_empty_iterator_should_render_nothing() {
+    let element: Element = core::iter::empty().collect();
+    assert_eq!(element.to_string(), "");
+    assert_eq!(elt::tbody([], [element]).to_string(), "<tbody></tbody>");
+}
+
+#[test]
+fn collected_elements_should_be_rendered_in_order_without_wrapper() {
+    let rows: Element = ["a", "b", "c"]
+        .into_iter()
+        .map(|s| elt::tr([], [elt::td([], [s.into()])]))
+        .collect();
+    assert_eq!(
+        elt::tbody([], [rows]).to_string(),
+        "<tbody><tr><td>a</td></tr><tr><td>b</td></tr><tr><td>c</td></tr></tbody>"
+    );
+}
+
+#[rstest]
+#[case(Vec::new().into_iter().collect(), "ab")]
+#[case([elt::text("1")].into_iter().collect(), "1ab")]
+#[case(elt::none(), "ab")]
+#[case(elt::text("1"), "1ab")]
+#[case(elt::div([], [elt::text("1")]), "<div>1ab</div>")]
+fn extend_should_append_elements(#[case] mut element: Element, #[case] expected: &str) {
+    element.extend([elt::text("a")]);
+    element.extend(vec![elt::text("b")]);
+    assert_eq!(element.to_string(), expected);
+}
+
+#[test]
+fn with_children_should_accept_any_iterator_of_elements() {
+    let extra: Option<Element> = None;
+    let list = elt::ul([], [])
+        .with_children((1..=2).map(|i| elt::li([], [i.to_string().into()])))
+        .with_children(vec![elt::li([], ["3".into()])])
+        .with_children(Some(elt::li([], ["4".into()])))
+        .with_children(extra)
+        .with_children(elt::li([], ["5".into()]));
+    assert_eq!(
+        list.to_string(),
+        "<ul><li>1</li><li>2</li><li>3</li><li>4</li><li>5</li></ul>"
+    );
+}
+
+#[rstest]
+#[case(true, "<div id=\"x\" class=\"active\"></div>")]
+#[case(false, "<div id=\"x\"></div>")]
+fn maybe_attribute_should_only_be_rendered_if_condition_is_true(
+    #[case] active: bool,
+    #[case] expected: &str,
+) {
+    let element = elt::div(
+        attr::id("x")
+            .into_iter()
+            .chain(Attribute::maybe(active, attr::class(["active"]))),
+        [],
+    );
+    assert_eq!(element.to_string(), expected);
+}
+
+#[rstest]
+#[case(true, "<div class=\"foo active\"></div>")]
+#[case(false, "<div class=\"foo\"></div>")]
+fn optional_attributes_should_be_skipped_when_none(#[case] active: bool, #[case] expected: &str) {
+    let options = [
+        Some(attr::class(["foo"])),
+        if active {
+            Some(attr::class(["active"]))
+        } else {
+            None
+        },
+    ];
+    assert_eq!(
+        elt::div(options.clone().map(Attribute::from), []).to_string(),
+        expected
+    );
+    assert_eq!(
+        elt::div(options.into_iter().flatten(), []).to_string(),
+        expected
+    );
+}
+
+#[test]
+fn none_attribute_should_render_nothing() {
+    assert_eq!(Attribute::from(None).to_string(), "");
+    assert_eq!(
+        elt::input([None::<Attribute>.into(), attr::required()]).to_string(),
+        "<input required>"
+    );
+}
+
+struct Tagged(&'static str);
+
+impl std::fmt::Display for Tagged {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}>", self.0)
+    }
+}
+
+#[rstest]
+#[case(elt::text_display(42), "42")]
+#[case(elt::text_display(-1.25), "-1.25")]
+#[case(elt::text_display(Tagged("b")), "&lt;b&gt;")]
+#[case(elt::text_display('&'), "&amp;")]
+#[case(42_u32.into(), "42")]
+#[case((-42_i32).into(), "-42")]
+#[case(u64::MAX.into(), "18446744073709551615")]
+#[case(i64::MIN.into(), "-9223372036854775808")]
+#[case(3_usize.into(), "3")]
+#[case(0.5_f64.into(), "0.5")]
+fn should_render_displayable_values_as_escaped_text(
+    #[case] element: Element,
+    #[case] expected: &str,
+) {
+    assert_eq!(element.to_string(), expected);
+}
+
+#[test]
+fn numbers_should_be_usable_as_children() {
+    let row = elt::tr([], [elt::td([], [1_u32.into()]), elt::td([], [2.5.into()])]);
+    assert_eq!(row.to_string(), "<tr><td>1</td><td>2.5</td></tr>");
+}
+
+#[rstest]
+#[case(Vec::new(), "")]
+#[case(vec![elt::text("a")], "a")]
+#[case(vec![elt::text("a"), elt::text("b"), elt::text("c")], "a, b, c")]
+fn join_should_put_text_separator_between_items(
+    #[case] items: Vec<Element>,
+    #[case] expected: &str,
+) {
+    assert_eq!(
+        elt::join(items.clone(), elt::text(", ")).to_string(),
+        expected
+    );
+    assert_eq!(
+        elt::join_with(items, || elt::text(", ")).to_string(),
+        expected
+    );
+}
+
+#[rstest]
+#[case(0, "<p></p>")]
+#[case(1, "<p>0</p>")]
+#[case(3, "<p>0<br>1<br>2</p>")]
+fn join_should_put_element_separator_between_items(#[case] len: usize, #[case] expected: &str) {
+    let items = (0..len).map(|i| elt::text(i.to_string()));
+    assert_eq!(
+        elt::p([], [elt::join(items, elt::br([]))]).to_string(),
+        expected
+    );
+}
+
+#[test]
+fn join_with_should_build_a_new_separator_for_each_gap() {
+    let mut count = 0;
+    let element = elt::join_with(["a", "b", "c"].map(elt::text), || {
+        count += 1;
+        elt::text(format!("[{count}]"))
+    });
+    assert_eq!(element.to_string(), "a[1]b[2]c");
+}
+
+#[test]
+fn html_element_should_render_like_document_without_doctype() {
+    let children = || {
+        [
+            elt::head([], [elt::title([], "T")]),
+            elt::body([], ["<b>".into()]),
+        ]
+    };
+    let attributes = || [attr::lang("en"), attr::dir(Dir::Ltr)];
+    assert_eq!(
+        elt::html_element(attributes(), children()).to_string(),
+        fun_html::html(attributes(), children())
+            .without_doctype()
+            .to_string()
+    );
+}
+
+#[rstest]
+#[case(elt::ul_from([], []), "<ul></ul>")]
+#[case(elt::ol_from([attr::id("x")], []), "<ol id=\"x\"></ol>")]
+#[case(elt::ul_from([], ["a", "<b>"].map(elt::text)), "<ul><li>a</li><li>&lt;b&gt;</li></ul>")]
+#[case(
+    elt::ol_from([attr::class(["steps"])], (1..=3).map(|i| elt::text(format!("step {i}")))),
+    "<ol class=\"steps\"><li>step 1</li><li>step 2</li><li>step 3</li></ol>"
+)]
+#[case(elt::ul_from([], [elt::li([], ["a".into()])]), "<ul><li><li>a</li></li></ul>")]
+#[case(fun_html::conv::ul_from(["a".into()]), "<ul><li>a</li></ul>")]
+#[case(fun_html::conv::ol_from(Vec::new()), "<ol></ol>")]
+fn list_from_items_should_wrap_each_item_in_li(#[case] element: Element, #[case] expected: &str) {
+    assert_eq!(element.to_string(), expected);
+}
+
+#[test]
+fn doctype_element_should_render_a_full_page_from_elements() {
+    let page = elt::fragment([
+        elt::doctype(),
+        elt::html_element([attr::lang("en")], [elt::head([], []), elt::body([], [])]),
+    ]);
+    assert_eq!(
+        page.to_string(),
+        "<!DOCTYPE html><html lang=\"en\"><head></head><body></body></html>"
+    );
+    assert_eq!(elt::doctype().to_string(), "<!DOCTYPE html>");
+}
+
+#[rstest]
+#[case(true, "<p>shown</p>", 1)]
+#[case(false, "", 0)]
+fn show_if_should_only_call_closure_when_condition_is_true(
+    #[case] condition: bool,
+    #[case] expected: &str,
+    #[case] expected_calls: usize,
+) {
+    let mut calls = 0;
+    let element = elt::show_if(condition, || {
+        calls += 1;
+        elt::p([], ["shown".into()])
+    });
+    assert_eq!(element.to_string(), expected);
+    assert_eq!(calls, expected_calls);
+}
+
+#[rstest]
+#[case(true, "then", (1, 0))]
+#[case(false, "otherwise", (0, 1))]
+fn show_if_else_should_only_call_closure_of_matching_branch(
+    #[case] condition: bool,
+    #[case] expected: &str,
+    #[case] expected_calls: (usize, usize),
+) {
+    let mut calls = (0, 0);
+    let element = elt::show_if_else(
+        condition,
+        || {
+            calls.0 += 1;
+            elt::text("then")
+        },
+        || {
+            calls.1 += 1;
+            elt::text("otherwise")
+        },
+    );
+    assert_eq!(element.to_string(), expected);
+    assert_eq!(calls, expected_calls);
+}
+
+#[rstest]
+#[case(Some(3), "<li>3</li>", 1)]
+#[case(None, "", 0)]
+fn maybe_should_only_call_closure_for_some_value(
+    #[case] value: Option<u32>,
+    #[case] expected: &str,
+    #[case] expected_calls: usize,
+) {
+    let mut calls = 0;
+    let element = elt::maybe(value, |value| {
+        calls += 1;
+        elt::li([], [value.into()])
+    });
+    assert_eq!(element.to_string(), expected);
+    assert_eq!(calls, expected_calls);
+}
+
+#[test]
+fn conditional_helpers_should_compose_inside_children() {
+    let admin = false;
+    let user: Option<&'static str> = Some("<Jo>");
+    let menu = elt::ul(
+        [],
+        [
+            elt::li([], ["Home".into()]),
+            elt::show_if(admin, || elt::li([], ["Admin".into()])),
+            elt::maybe(user, |user| elt::li([], [user.into()])),
+            elt::show_if_else(user.is_some(), || "Log out".into(), || "Log in".into()),
+        ],
+    );
+    assert_eq!(
+        menu.to_string(),
+        "<ul><li>Home</li><li>&lt;Jo&gt;</li>Log out</ul>"
+    );
+}
+
+fn add_class_to_tables(element: Element) -> Element {
+    match element.tag() {
+        Some("table") => element.with_attribute(attr::class(["table"])),
+        _ => element,
+    }
+}
+
+#[test]
+fn map_should_transform_every_matching_element() {
+    let body = || {
+        elt::body(
+            [],
+            [
+                elt::h1([attr::id("t")], ["Report <1>".into()]),
+                elt::table(
+                    [attr::class(["wide"])],
+                    [elt::tr(
+                        [],
+                        [elt::td(
+                            [],
+                            [elt::table([], [elt::tr([], [elt::td([], ["b".into()])])])],
+                        )],
+                    )],
+                ),
+                elt::fragment([elt::table([], []), elt::comment("end")]),
+                elt::raw("<table>"),
+            ],
+        )
+    };
+    assert_eq!(
+        body().map(add_class_to_tables).to_string(),
+        concat!(
+            "<body><h1 id=\"t\">Report &lt;1&gt;</h1>",
+            "<table class=\"wide table\"><tr><td><table class=\"table\"><tr><td>b</td></tr></table></td></tr></table>",
+            "<table class=\"table\"></table><!--end--><table></body>"
+        )
+    );
+}
+
+#[rstest]
+#[case(elt::none())]
+#[case(elt::text("<a & b>"))]
+#[case(elt::div([attr::id("x"), attr::class(["a"])], [elt::br([]), elt::comment("c"), elt::raw("<b>")]))]
+#[case(document_with_tables_and_pre().body().unwrap().clone())]
+#[case(elt::lazy(|| elt::table([], [])))]
+fn map_should_preserve_untouched_elements(#[case] element: Element) {
+    let expected = element.to_string();
+    assert_eq!(element.clone().map(|e| e).to_string(), expected);
+    if !expected.contains("<table") {
+        assert_eq!(element.map(add_class_to_tables).to_string(), expected);
+    }
+}
+
+#[test]
+fn map_should_transform_children_before_parents() {
+    let order = std::cell::RefCell::new(Vec::new());
+    let element = elt::div([], [elt::p([], [elt::text("a")]), elt::br([])]);
+    let _ = element.map(|e| {
+        order.borrow_mut().push(e.tag().unwrap_or("text"));
+        e
+    });
+    assert_eq!(order.into_inner(), ["text", "p", "br", "div"]);
+}
+
+#[test]
+fn visit_mut_should_visit_every_element_in_the_same_order_as_map() {
+    let mut element = elt::div([], [elt::p([], [elt::text("a")]), elt::br([])]);
+    let mut order = Vec::new();
+    element.visit_mut(|e| {
+        order.push(e.tag().unwrap_or("text"));
+        if e.tag() == Some("br") {
+            *e = elt::hr([]);
+        }
+    });
+    assert_eq!(order, ["text", "p", "br", "div"]);
+    assert_eq!(element.to_string(), "<div><p>a</p><hr></div>");
+}
+
+#[test]
+fn element_should_expose_tag_attributes_and_children() {
+    let mut element = elt::div(
+        [attr::id("x"), attr::required(), attr::none()],
+        [elt::text("a"), elt::br([])],
+    );
+    assert_eq!(element.tag(), Some("div"));
+    assert_eq!(
+        element
+            .attributes()
+            .iter()
+            .map(Attribute::name)
+            .collect::<Vec<_>>(),
+        [Some("id"), Some("required"), None]
+    );
+    assert_eq!(
+        element.attributes()[0].value().and_then(|v| v.as_str()),
+        Some("x")
+    );
+    assert!(element.attributes()[1].value().is_none());
+    assert_eq!(
+        element
+            .children()
+            .iter()
+            .map(Element::tag)
+            .collect::<Vec<_>>(),
+        [None, Some("br")]
+    );
+    element
+        .attributes_mut()
+        .unwrap()
+        .retain(|a| a.name() != Some("required"));
+    assert_eq!(element.to_string(), "<div id=\"x\">a<br></div>");
+
+    let mut text = elt::text("a");
+    assert_eq!(text.tag(), None);
+    assert!(text.attributes().is_empty());
+    assert!(text.attributes_mut().is_none());
+    assert!(text.children().is_empty());
+    assert_eq!(elt::fragment([elt::br([])]).children().len(), 1);
+    assert_eq!(elt::br([attr::id("b")]).attributes().len(), 1);
+}
+
+#[test]
+fn table_from_rows_without_rows_should_only_have_a_header() {
+    let table = elt::table_from_rows([], ["A", "B"].map(elt::text), Vec::<Vec<Element>>::new());
+    assert_eq!(
+        table.to_string(),
+        "<table><thead><tr><th>A</th><th>B</th></tr></thead></table>"
+    );
+}
+
+#[test]
+fn table_from_rows_should_wrap_headers_and_cells() {
+    let rows = [(1, "a"), (2, "<b>")].iter().map(|(n, s)| {
+        [
+            elt::text(n.to_string()),
+            elt::text(*s),
+            elt::text(format!("{s}{n}")),
+        ]
+    });
+    let table = elt::table_from_rows(
+        [attr::class(["report"])],
+        ["N", "S", "Both"].map(elt::text),
+        rows,
+    );
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "<table class=\"report\">",
+            "<thead><tr><th>N</th><th>S</th><th>Both</th></tr></thead>",
+            "<tbody>",
+            "<tr><td>1</td><td>a</td><td>a1</td></tr>",
+            "<tr><td>2</td><td>&lt;b&gt;</td><td>&lt;b&gt;2</td></tr>",
+            "</tbody></table>"
+        )
+    );
+}
+
+#[test]
+fn table_from_rows_should_render_ragged_rows_as_is() {
+    let table = fun_html::conv::table_from_rows(
+        ["A", "B"].map(elt::text),
+        vec![
+            vec![elt::text("1")],
+            vec![],
+            vec![elt::text("2"), elt::text("3"), elt::text("4")],
+        ],
+    );
+    assert_eq!(
+        table.to_string(),
+        "<table><thead><tr><th>A</th><th>B</th></tr></thead><tbody><tr><td>1</td></tr><tr></tr><tr><td>2</td><td>3</td><td>4</td></tr></tbody></table>"
+    );
+}
+
+#[rstest]
+#[case(Some("b"), "<select name=\"x\"><option value=\"a\">A</option><option value=\"b\" selected>B</option></select>")]
+#[case(
+    Some("c"),
+    "<select name=\"x\"><option value=\"a\">A</option><option value=\"b\">B</option></select>"
+)]
+#[case(
+    None,
+    "<select name=\"x\"><option value=\"a\">A</option><option value=\"b\">B</option></select>"
+)]
+fn select_with_options_should_select_matching_value(
+    #[case] selected: Option<&str>,
+    #[case] expected: &str,
+) {
+    let select = elt::select_with_options([attr::name("x")], [("a", "A"), ("b", "B")], selected);
+    assert_eq!(select.to_string(), expected);
+}
+
+#[test]
+fn select_with_options_should_escape_values_and_labels() {
+    let options = vec![
+        (String::from("say \"hi\""), String::from("<Hi>")),
+        (String::from("a&b"), String::from("A & B")),
+    ];
+    let select = elt::select_with_options([], options, Some("say \"hi\""));
+    assert_eq!(
+        select.to_string(),
+        "<select><option value=\"say &quot;hi&quot;\" selected>&lt;Hi&gt;</option><option value=\"a&amp;b\">A &amp; B</option></select>"
+    );
+}
+
+#[rstest]
+#[case(elt::input_text("q", "a \"b\"", []), "<input type=\"text\" name=\"q\" value=\"a &quot;b&quot;\">")]
+#[case(elt::input_text("q", "", [attr::placeholder("Search"), attr::required()]), "<input type=\"text\" name=\"q\" value=\"\" placeholder=\"Search\" required>")]
+#[case(elt::input_hidden("csrf", String::from("t<k>n"), []), "<input type=\"hidden\" name=\"csrf\" value=\"t&lt;k&gt;n\">")]
+#[case(elt::input_password("pwd", [attr::autocomplete_off()]), "<input type=\"password\" name=\"pwd\" autocomplete=\"off\">")]
+#[case(elt::input_checkbox("remember", true, []), "<input type=\"checkbox\" name=\"remember\" checked>")]
+#[case(elt::input_checkbox("remember", false, [attr::id("r")]), "<input type=\"checkbox\" name=\"remember\" id=\"r\">")]
+#[case(elt::input_radio("size", "s", true, []), "<input type=\"radio\" name=\"size\" value=\"s\" checked>")]
+#[case(elt::input_radio("size", "l", false, [attr::disabled()]), "<input type=\"radio\" name=\"size\" value=\"l\" disabled>")]
+#[case(elt::input_submit("Send", []), "<input type=\"submit\" value=\"Send\">")]
+#[case(elt::input_submit("Send & go", [attr::class(["btn"])]), "<input type=\"submit\" value=\"Send &amp; go\" class=\"btn\">")]
+fn typed_input_constructors_should_render_expected_attributes(
+    #[case] element: Element,
+    #[case] expected: &str,
+) {
+    assert_eq!(element.to_string(), expected);
+}
+
+mod glob_imports {
+    use fun_html::elt::*;
+    use fun_html::*;
+
+    #[test]
+    fn html_should_not_be_ambiguous_when_glob_importing_the_crate_and_elt() {
+        let doc: Document = html([], [head([], []), body([], [])]);
+        assert_eq!(
+            doc.to_string(),
+            "<!DOCTYPE html>\n<html><head></head><body></body></html>"
+        );
+    }
+}
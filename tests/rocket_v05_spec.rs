@@ -53,3 +53,14 @@ fn element_should_implement_response(client: Client) {
         "<div id=\"foo\">hello</div>"
     );
 }
+
+#[rstest]
+#[case("/document")]
+#[case("/fragment")]
+fn response_should_declare_utf8_html_content_type(client: Client, #[case] path: &str) {
+    let response = client.get(path).dispatch();
+    assert_eq!(
+        response.headers().get_one("content-type"),
+        Some("text/html; charset=utf-8")
+    );
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "serde_json")]
+
+use std::collections::BTreeMap;
+
+use rstest::rstest;
+use serde::{Deserialize, Serialize};
+
+use fun_html::{elt::script_json, Element};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct State {
+    title: String,
+    tags: Vec<String>,
+}
+
+fn state() -> State {
+    State {
+        title: "</script><script>alert(1)</script>".into(),
+        tags: vec!["<!-- a & b -->".into(), "é > ü".into()],
+    }
+}
+
+#[test]
+fn should_render_inert_json_script() {
+    let html = script_json("state", &state()).unwrap().to_string();
+    let (prefix, rest) = html.split_at("<script type=\"application/json\" id=\"state\">".len());
+    assert_eq!(prefix, "<script type=\"application/json\" id=\"state\">");
+    let text = rest.strip_suffix("</script>").unwrap();
+    assert!(!text.contains(['<', '>', '&']), "{text}");
+    assert_eq!(serde_json::from_str::<State>(text).unwrap(), state());
+}
+
+#[rstest]
+#[case(script_json("data", &1), "1")]
+#[case(script_json("data", &"a<b"), "\"a\\u003cb\"")]
+#[case(script_json("data", &Option::<u8>::None), "null")]
+fn should_render_json(#[case] element: Result<Element, serde_json::Error>, #[case] expected: &str) {
+    assert_eq!(
+        element.unwrap().to_string(),
+        format!("<script type=\"application/json\" id=\"data\">{expected}</script>")
+    );
+}
+
+#[test]
+fn should_return_serialization_error() {
+    let value: BTreeMap<(u8, u8), u8> = [((1, 2), 3)].into_iter().collect();
+    assert!(script_json("data", &value).is_err());
+}
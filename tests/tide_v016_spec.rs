@@ -0,0 +1,29 @@
+#![cfg(feature = "tide_v016")]
+
+use rstest::rstest;
+use tide_v016::{http::mime, Response, StatusCode};
+
+use fun_html::{
+    attr::id,
+    elt::{body, div, head},
+    html,
+};
+
+#[rstest]
+#[case(
+    html([], [head([], []), body([], ["hello".into()])]).into(),
+    "<!DOCTYPE html>\n<html><head></head><body>hello</body></html>"
+)]
+#[case(div([id("foo")], ["hello".into()]).into(), "<div id=\"foo\">hello</div>")]
+#[tokio::test]
+async fn should_convert_into_html_response(
+    #[case] mut response: Response,
+    #[case] expected_body: &str,
+) {
+    assert_eq!(response.status(), StatusCode::Ok);
+    assert_eq!(response.content_type(), Some(mime::HTML));
+    assert_eq!(
+        response.take_body().into_string().await.unwrap(),
+        expected_body
+    );
+}
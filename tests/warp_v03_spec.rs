@@ -0,0 +1,27 @@
+#![cfg(feature = "warp_v03")]
+
+use rstest::rstest;
+use warp_v03::{http::header::CONTENT_TYPE, hyper::body::to_bytes, reply::Response, Reply};
+
+use fun_html::{
+    attr::id,
+    elt::{body, div, head},
+    html,
+};
+
+#[rstest]
+#[case(
+    html([], [head([], []), body([], ["hello".into()])]).into_response(),
+    "<!DOCTYPE html>\n<html><head></head><body>hello</body></html>"
+)]
+#[case(div([id("foo")], ["hello".into()]).into_response(), "<div id=\"foo\">hello</div>")]
+#[tokio::test]
+async fn should_reply_with_html(#[case] response: Response, #[case] expected_body: &str) {
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.headers().get(CONTENT_TYPE).unwrap(),
+        "text/html; charset=utf-8"
+    );
+    let bytes = to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(bytes, expected_body);
+}